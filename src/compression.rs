@@ -0,0 +1,86 @@
+use std::path::Path;
+
+/// Name of the file, located at the root of a repository, that records the
+/// format of the repository (*i.e. the compression of its files*).
+pub const FORMAT_MARKER: &str = ".jaburepo";
+
+/// Magic number at the start of every zstd frame.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Compression level used for zstd. Text files are small, so there's little
+/// to gain from higher levels.
+const ZSTD_LEVEL: i32 = 3;
+
+/// Compression applied to the text files stored in a repository (*poms, Gradle module
+/// metadata and `maven-metadata.xml` files*). Jars are never compressed, since they're
+/// already compressed archives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    #[default]
+    None,
+    Zstd,
+}
+
+impl Compression {
+    /// Name of the compression, as written in the format marker.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Zstd => "zstd",
+        }
+    }
+
+    /// Inverse of [`Self::name`].
+    pub fn from_name<T: AsRef<str>>(name: T) -> Option<Self> {
+        match name.as_ref() {
+            "none" => Some(Self::None),
+            "zstd" => Some(Self::Zstd),
+            _ => None,
+        }
+    }
+
+    /// Compresses the given contents.
+    pub fn compress(&self, contents: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(contents.to_vec()),
+            Self::Zstd => zstd::encode_all(contents, ZSTD_LEVEL),
+        }
+    }
+}
+
+/// Decompresses the contents of a stored file. The compression is detected from the
+/// contents themselves, so files stored with any compression (*or none*) can be read,
+/// regardless of the current compression of the repository.
+pub fn decompress(stored: Vec<u8>) -> std::io::Result<Vec<u8>> {
+    if stored.starts_with(&ZSTD_MAGIC) {
+        zstd::decode_all(stored.as_slice())
+    } else {
+        Ok(stored)
+    }
+}
+
+/// Reads the compression recorded in the format marker of the repository located at
+/// `base_path`. Repositories without a marker (*or with an unknown compression*)
+/// aren't compressed.
+pub(crate) fn read_format_marker(base_path: &Path) -> Compression {
+    std::fs::read_to_string(base_path.join(FORMAT_MARKER))
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .find(|(key, _)| key.trim() == "compression")
+        .and_then(|(_, value)| Compression::from_name(value.trim()))
+        .unwrap_or_default()
+}
+
+/// Records the given compression in the format marker of the repository located
+/// at `base_path`.
+pub(crate) fn write_format_marker(
+    base_path: &Path,
+    compression: Compression,
+) -> std::io::Result<()> {
+    std::fs::create_dir_all(base_path)?;
+    std::fs::write(
+        base_path.join(FORMAT_MARKER),
+        format!("compression={}\n", compression.name()),
+    )
+}
@@ -0,0 +1,178 @@
+use crate::error::RepositoryOperationError;
+use crate::files::{FileKind, FileRole, FileSetOptions};
+use crate::repository::{Artifact, RemoteRepository, Repository};
+use std::path::PathBuf;
+use url::Url;
+
+/// Options of a deployment to a remote repository.
+#[derive(Debug, Clone, Default)]
+pub struct DeployOptions {
+    /// If enabled, nothing gets uploaded, and the report returned contains
+    /// the files that would have been uploaded.
+    pub dry_run: bool,
+
+    /// User used to authenticate against the remote repository (*basic auth*).
+    pub username: Option<String>,
+
+    /// Password used to authenticate against the remote repository (*basic auth*).
+    pub password: Option<String>,
+}
+
+/// A file uploaded (*or to be uploaded, in a dry run*) to the remote repository.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeployedFile {
+    /// Location of the file in the local repository. The checksums and the
+    /// `maven-metadata.xml` get computed when deploying, so they might not have a
+    /// file in the local repository (*or it might differ from the one uploaded*).
+    pub local_path: PathBuf,
+
+    /// Location the file gets PUT to.
+    pub remote_url: Url,
+}
+
+/// Report of a deployment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeployReport {
+    pub artifact: Artifact,
+
+    /// Whether the deployment was a dry run, meaning that nothing was uploaded.
+    pub dry_run: bool,
+
+    /// Files uploaded, in the order they were uploaded.
+    pub files: Vec<DeployedFile>,
+}
+
+impl std::fmt::Display for DeployReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let action = if self.dry_run { "would PUT" } else { "PUT" };
+        for file in &self.files {
+            writeln!(
+                f,
+                "{} {action} {}",
+                file.local_path.display(),
+                file.remote_url
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks whether the version is a snapshot, which can be redeployed.
+fn is_snapshot(artifact: &Artifact) -> bool {
+    artifact.version.ends_with("-SNAPSHOT")
+}
+
+/// Uploads the files of the artifact in the local repository to the remote repository.
+pub(crate) fn deploy(
+    repository: &Repository,
+    artifact: &Artifact,
+    remote_repository: &RemoteRepository,
+    options: &DeployOptions,
+) -> crate::RepositoryOperationResult<DeployReport> {
+    // Read everything before uploading anything, so a missing file doesn't
+    // result in a partial deployment.
+    let files = crate::files::artifact_files(
+        artifact,
+        &FileSetOptions {
+            sources: true,
+            javadoc: true,
+            module: true,
+        },
+    );
+    let mut uploads: Vec<(DeployedFile, Vec<u8>)> = Vec::new();
+    let mut contents: Option<Vec<u8>> = None;
+    for file in files {
+        let local_path = file.local_path(repository);
+        let body = match file.role {
+            FileRole::Content => {
+                contents = if file.kind == FileKind::Pom || local_path.exists() {
+                    Some(repository.read_stored_file(&local_path)?)
+                } else {
+                    None
+                };
+                contents.clone()
+            }
+            // Computed instead of read, so they match the contents uploaded.
+            FileRole::Checksum => contents
+                .as_ref()
+                .map(|contents| crate::utils::sha1_hex(contents).into_bytes()),
+            FileRole::Signature if contents.is_some() && local_path.exists() => {
+                Some(std::fs::read(&local_path)?)
+            }
+            FileRole::Signature => None,
+        };
+
+        if let Some(body) = body {
+            uploads.push((
+                DeployedFile {
+                    remote_url: file.remote_url(remote_repository)?,
+                    local_path,
+                },
+                body,
+            ));
+        }
+    }
+
+    let pom_url = remote_repository.pom_artifact_url(artifact)?;
+    let client = reqwest::blocking::Client::new();
+    let authenticate = |request: reqwest::blocking::RequestBuilder| match &options.username {
+        Some(username) => request.basic_auth(username, options.password.as_ref()),
+        None => request,
+    };
+
+    // Release versions are immutable, so the pom being present means the version
+    // has already been deployed. Only a `404 Not Found` means it's missing, any other
+    // failure (*i.e. the credentials being rejected*) fails the deployment.
+    if !is_snapshot(artifact) {
+        let response = authenticate(client.head(pom_url)).send()?;
+        if response.status().is_success() {
+            return Err(RepositoryOperationError::VersionAlreadyDeployed(
+                artifact.clone(),
+            ));
+        } else if response.status() != reqwest::StatusCode::NOT_FOUND {
+            response.error_for_status()?;
+        }
+    }
+
+    // The metadata is how the versions deployed get discovered, so the version gets
+    // added to the metadata already in the remote repository (*if any*).
+    let metadata_url = remote_repository.metadata_artifact_url(artifact)?;
+    let response = authenticate(client.get(metadata_url.clone())).send()?;
+    let remote_metadata = if response.status() == reqwest::StatusCode::NOT_FOUND {
+        None
+    } else {
+        Some(response.error_for_status()?.text()?)
+    };
+    let metadata = crate::utils::metadata_with_version(remote_metadata.as_deref(), artifact)?;
+    let metadata_checksum = crate::utils::sha1_hex(&metadata).into_bytes();
+    let metadata_path = repository.artifact_metadata_path(artifact);
+    uploads.push((
+        DeployedFile {
+            local_path: metadata_path.clone(),
+            remote_url: metadata_url.clone(),
+        },
+        metadata.into_bytes(),
+    ));
+    uploads.push((
+        DeployedFile {
+            local_path: crate::utils::checksum_path(&metadata_path),
+            remote_url: Url::parse(&format!("{metadata_url}.sha1"))?,
+        },
+        metadata_checksum,
+    ));
+
+    if !options.dry_run {
+        for (file, contents) in &uploads {
+            authenticate(client.put(file.remote_url.clone()))
+                .body(contents.clone())
+                .send()?
+                .error_for_status()?;
+        }
+    }
+
+    Ok(DeployReport {
+        artifact: artifact.clone(),
+        dry_run: options.dry_run,
+        files: uploads.into_iter().map(|(file, _)| file).collect(),
+    })
+}
@@ -0,0 +1,244 @@
+use crate::repository::Artifact;
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{Read, Seek},
+    path::Path,
+};
+use zip::{result::ZipResult, ZipArchive};
+
+/// Location of the manifest inside of a jar.
+const MANIFEST_PATH: &str = "META-INF/MANIFEST.MF";
+
+/// A file contained in a jar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JarEntry {
+    pub name: String,
+
+    /// Uncompressed size of the entry.
+    pub size: u64,
+}
+
+/// A file present in both jars, but with different contents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangedEntry {
+    pub name: String,
+    pub old_size: u64,
+    pub new_size: u64,
+}
+
+impl ChangedEntry {
+    /// Difference in size between the new and the old entry.
+    pub fn size_delta(&self) -> i64 {
+        self.new_size as i64 - self.old_size as i64
+    }
+}
+
+/// A main attribute of the manifest that has been added, removed or modified. The
+/// value is `None` on the side the attribute is missing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestChange {
+    pub attribute: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+/// Differences between the jars of two versions of the same artifact.
+///
+/// The entries and attributes are sorted by name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtifactDiff {
+    pub old: Artifact,
+    pub new: Artifact,
+
+    /// Size of the old jar.
+    pub old_size: u64,
+
+    /// Size of the new jar.
+    pub new_size: u64,
+
+    /// Entries only present in the new jar.
+    pub added: Vec<JarEntry>,
+
+    /// Entries only present in the old jar.
+    pub removed: Vec<JarEntry>,
+
+    /// Entries present in both jars whose contents differ.
+    pub changed: Vec<ChangedEntry>,
+
+    /// Changes in the main attributes of the manifest (*`META-INF/MANIFEST.MF`*).
+    pub manifest_changes: Vec<ManifestChange>,
+}
+
+impl ArtifactDiff {
+    /// Difference in size between the new and the old jar.
+    pub fn size_delta(&self) -> i64 {
+        self.new_size as i64 - self.old_size as i64
+    }
+
+    /// Whether the contents of both jars are the same (*ignoring timestamps
+    /// and compression*).
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.manifest_changes.is_empty()
+    }
+}
+
+impl std::fmt::Display for ArtifactDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{}:{}: {} -> {} ({:+} bytes)",
+            self.old.group_id,
+            self.old.artifact_id,
+            self.old.version,
+            self.new.version,
+            self.size_delta()
+        )?;
+        for entry in &self.added {
+            writeln!(f, "+ {} ({} bytes)", entry.name, entry.size)?;
+        }
+        for entry in &self.removed {
+            writeln!(f, "- {} ({} bytes)", entry.name, entry.size)?;
+        }
+        for entry in &self.changed {
+            writeln!(f, "~ {} ({:+} bytes)", entry.name, entry.size_delta())?;
+        }
+        for change in &self.manifest_changes {
+            writeln!(
+                f,
+                "manifest {}: {} -> {}",
+                change.attribute,
+                change.old_value.as_deref().unwrap_or("(none)"),
+                change.new_value.as_deref().unwrap_or("(none)")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Contents of a jar relevant for a diff.
+struct JarSummary {
+    size: u64,
+
+    /// Size and CRC-32 of every file, by name.
+    entries: BTreeMap<String, (u64, u32)>,
+    manifest: BTreeMap<String, String>,
+}
+
+fn summarize_jar<R: Read + Seek>(reader: R, size: u64) -> ZipResult<JarSummary> {
+    let mut archive = ZipArchive::new(reader)?;
+    let mut entries = BTreeMap::new();
+    let mut manifest = BTreeMap::new();
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        if entry.is_dir() {
+            continue;
+        }
+        if entry.name() == MANIFEST_PATH {
+            let mut contents = String::new();
+            entry.read_to_string(&mut contents)?;
+            manifest = manifest_main_attributes(&contents);
+        }
+        entries.insert(entry.name().to_string(), (entry.size(), entry.crc32()));
+    }
+
+    Ok(JarSummary {
+        size,
+        entries,
+        manifest,
+    })
+}
+
+/// Parses the main section of a manifest (*everything before the first empty line*),
+/// joining the continuation lines (*lines starting with a space*) with the previous ones.
+fn manifest_main_attributes(contents: &str) -> BTreeMap<String, String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in contents.lines() {
+        if line.is_empty() {
+            break;
+        }
+        match (line.strip_prefix(' '), lines.last_mut()) {
+            (Some(continuation), Some(previous)) => previous.push_str(continuation),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    lines
+        .iter()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+/// Compares the jars located at the given paths.
+pub(crate) fn diff_jars(
+    old: &Artifact,
+    old_path: &Path,
+    new: &Artifact,
+    new_path: &Path,
+) -> crate::RepositoryOperationResult<ArtifactDiff> {
+    let old_file = File::open(old_path)?;
+    let old_summary = summarize_jar(&old_file, old_file.metadata()?.len())?;
+    let new_file = File::open(new_path)?;
+    let new_summary = summarize_jar(&new_file, new_file.metadata()?.len())?;
+
+    let mut diff = ArtifactDiff {
+        old: old.clone(),
+        new: new.clone(),
+        old_size: old_summary.size,
+        new_size: new_summary.size,
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+        manifest_changes: Vec::new(),
+    };
+
+    for (name, &(old_size, old_crc)) in &old_summary.entries {
+        match new_summary.entries.get(name) {
+            None => diff.removed.push(JarEntry {
+                name: name.clone(),
+                size: old_size,
+            }),
+            Some(&(new_size, new_crc)) if new_size != old_size || new_crc != old_crc => {
+                diff.changed.push(ChangedEntry {
+                    name: name.clone(),
+                    old_size,
+                    new_size,
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    diff.added = new_summary
+        .entries
+        .iter()
+        .filter(|(name, _)| !old_summary.entries.contains_key(*name))
+        .map(|(name, &(size, _))| JarEntry {
+            name: name.clone(),
+            size,
+        })
+        .collect();
+
+    let mut attributes: Vec<&String> = old_summary
+        .manifest
+        .keys()
+        .chain(new_summary.manifest.keys())
+        .collect();
+    attributes.sort();
+    attributes.dedup();
+    diff.manifest_changes = attributes
+        .into_iter()
+        .map(|attribute| ManifestChange {
+            attribute: attribute.clone(),
+            old_value: old_summary.manifest.get(attribute).cloned(),
+            new_value: new_summary.manifest.get(attribute).cloned(),
+        })
+        .filter(|change| change.old_value != change.new_value)
+        .collect();
+
+    Ok(diff)
+}
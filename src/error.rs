@@ -1,3 +1,4 @@
+use crate::operation::{OperationId, Progress};
 use crate::repository::Artifact;
 use url::ParseError;
 
@@ -23,11 +24,197 @@ pub enum RepositoryOperationError {
     /// ***NOTE***: There are many chances this error is given when 
     /// trying to parse the pom of an artifact.
     SerdeXmlParsingError(serde_xml_rs::Error),
+
+    /// The coordinates of the file couldn't be inferred from neither
+    /// a pom, nor its filename.
+    UnresolvedCoordinates(std::path::PathBuf),
+
+    /// The same artifact was found more than once in a single operation
+    /// (*i.e. two jars in the same directory resolving to the same coordinates*).
+    DuplicateArtifact(Artifact),
+
+    /// The SHA-1 checksum of a file doesn't match the expected one.
+    ChecksumMismatch {
+        /// Location of the expected checksum.
+        file: String,
+        expected: String,
+        actual: String,
+    },
+
+    /// The pom of the artifact doesn't follow the POM schema. Only
+    /// given when the strict validation of poms is enabled.
+    PomSchemaViolation {
+        artifact: Artifact,
+        diagnostics: Vec<crate::validation::PomDiagnostic>,
+    },
+
+    /// The jar of an artifact couldn't be read as a zip archive.
+    JarError(zip::result::ZipError),
+
+    /// The operation expected two versions of the same artifact, but
+    /// the artifacts given have a different group id or artifact id.
+    MismatchedArtifacts(Box<Artifact>, Box<Artifact>),
+
+    /// The version of the artifact is already in the remote repository,
+    /// and it cannot be overwritten since it's a release.
+    VersionAlreadyDeployed(Artifact),
+
+    /// The file cannot be verified, since there's no checksum
+    /// stored next to it.
+    MissingChecksum(std::path::PathBuf),
+
+    /// The artifact isn't in the repository (*i.e. the sibling repository
+    /// an artifact is being adopted from*).
+    ArtifactNotFound(Artifact),
+
+    /// The operation was cancelled through its cancellation token
+    /// (*see [`crate::operation::CancellationToken`]*).
+    Cancelled {
+        operation: OperationId,
+        progress: Progress,
+    },
+
+    /// The deadline of the operation passed before it could finish.
+    DeadlineExceeded {
+        operation: OperationId,
+        progress: Progress,
+    },
+
+    /// A request to the remote repository timed out. Contains the URL
+    /// requested, if known.
+    NetworkTimeout {
+        operation: OperationId,
+        url: Option<String>,
+        progress: Progress,
+    },
+}
+
+impl RepositoryOperationError {
+    /// Progress made by the operation before being interrupted, if the error
+    /// was caused by an interruption (*a cancellation, a deadline or a timeout*).
+    pub fn progress(&self) -> Option<&Progress> {
+        match self {
+            Self::Cancelled { progress, .. }
+            | Self::DeadlineExceeded { progress, .. }
+            | Self::NetworkTimeout { progress, .. } => Some(progress),
+            _ => None,
+        }
+    }
+
+    /// Id of the operation interrupted, if the error was caused by an
+    /// interruption (*a cancellation, a deadline or a timeout*).
+    pub fn operation(&self) -> Option<OperationId> {
+        match self {
+            Self::Cancelled { operation, .. }
+            | Self::DeadlineExceeded { operation, .. }
+            | Self::NetworkTimeout { operation, .. } => Some(*operation),
+            _ => None,
+        }
+    }
+
+    /// Name of the variant of the error (*i.e. `checksum_mismatch`*), which stays the
+    /// same across versions, so tools consuming reports can rely on it.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::GetError(_) => "get_error",
+            Self::InvalidArtifactName(_) => "invalid_artifact_name",
+            Self::IoError(_) => "io_error",
+            Self::SerdeXmlParsingError(_) => "xml_parsing_error",
+            Self::UnresolvedCoordinates(_) => "unresolved_coordinates",
+            Self::DuplicateArtifact(_) => "duplicate_artifact",
+            Self::ChecksumMismatch { .. } => "checksum_mismatch",
+            Self::PomSchemaViolation { .. } => "pom_schema_violation",
+            Self::JarError(_) => "jar_error",
+            Self::MismatchedArtifacts(_, _) => "mismatched_artifacts",
+            Self::VersionAlreadyDeployed(_) => "version_already_deployed",
+            Self::MissingChecksum(_) => "missing_checksum",
+            Self::ArtifactNotFound(_) => "artifact_not_found",
+            Self::Cancelled { .. } => "cancelled",
+            Self::DeadlineExceeded { .. } => "deadline_exceeded",
+            Self::NetworkTimeout { .. } => "network_timeout",
+        }
+    }
+}
+
+/// Coordinates of the artifact (*`group_id:artifact_id:version`*).
+fn coordinates(artifact: &Artifact) -> String {
+    format!(
+        "{}:{}:{}",
+        artifact.group_id, artifact.artifact_id, artifact.version
+    )
 }
 
 impl std::fmt::Display for RepositoryOperationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self) // TODO
+        match self {
+            Self::GetError(e) => write!(f, "request to the remote repository failed: {e}"),
+            Self::InvalidArtifactName(e) => {
+                write!(f, "the artifact cannot be represented in a URL: {e}")
+            }
+            Self::IoError(e) => write!(f, "I/O error: {e}"),
+            Self::SerdeXmlParsingError(e) => write!(f, "malformed XML: {e}"),
+            Self::UnresolvedCoordinates(path) => {
+                write!(f, "couldn't resolve the coordinates of {}", path.display())
+            }
+            Self::DuplicateArtifact(artifact) => {
+                write!(f, "{} found more than once", coordinates(artifact))
+            }
+            Self::ChecksumMismatch {
+                file,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "checksum mismatch ({file}): expected {expected}, found {actual}"
+            ),
+            Self::PomSchemaViolation {
+                artifact,
+                diagnostics,
+            } => {
+                write!(
+                    f,
+                    "the pom of {} violates the POM schema",
+                    coordinates(artifact)
+                )?;
+                for diagnostic in diagnostics {
+                    write!(f, "\n  {diagnostic}")?;
+                }
+                Ok(())
+            }
+            Self::JarError(e) => write!(f, "the jar couldn't be read: {e}"),
+            Self::MismatchedArtifacts(old, new) => write!(
+                f,
+                "{} and {} aren't versions of the same artifact",
+                coordinates(old),
+                coordinates(new)
+            ),
+            Self::VersionAlreadyDeployed(artifact) => write!(
+                f,
+                "{} is already in the remote repository",
+                coordinates(artifact)
+            ),
+            Self::MissingChecksum(path) => {
+                write!(f, "there's no checksum for {}", path.display())
+            }
+            Self::ArtifactNotFound(artifact) => {
+                write!(f, "{} isn't in the repository", coordinates(artifact))
+            }
+            Self::Cancelled { operation, .. } => write!(f, "{operation} was cancelled"),
+            Self::DeadlineExceeded { operation, .. } => {
+                write!(f, "the deadline of {operation} passed")
+            }
+            Self::NetworkTimeout {
+                operation,
+                url: Some(url),
+                ..
+            } => write!(f, "request to {url} timed out ({operation})"),
+            Self::NetworkTimeout { operation, .. } => {
+                write!(
+                    f,
+                    "request to the remote repository timed out ({operation})"
+                )
+            }
+        }
     }
 }
 
@@ -55,4 +242,10 @@ impl From<reqwest::Error> for RepositoryOperationError {
     }
 }
 
+impl From<zip::result::ZipError> for RepositoryOperationError {
+    fn from(value: zip::result::ZipError) -> Self {
+        Self::JarError(value)
+    }
+}
+
 impl std::error::Error for RepositoryOperationError {}
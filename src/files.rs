@@ -0,0 +1,141 @@
+use crate::repository::{Artifact, RemoteRepository, Repository};
+use std::path::PathBuf;
+use url::{ParseError, Url};
+
+/// The different files of a version of an artifact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileKind {
+    Jar,
+    Sources,
+    Javadoc,
+
+    /// Gradle module metadata.
+    Module,
+    Pom,
+}
+
+impl FileKind {
+    /// Text appended to the version (*locally*) or to `artifactId-version` (*remotely*)
+    /// to form the name of the file.
+    fn suffix(&self) -> &'static str {
+        match self {
+            Self::Jar => ".jar",
+            Self::Sources => "-sources.jar",
+            Self::Javadoc => "-javadoc.jar",
+            Self::Module => ".module",
+            Self::Pom => ".pom",
+        }
+    }
+}
+
+/// What a file contains regarding its [`FileKind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileRole {
+    /// The file itself (*i.e. the jar*).
+    Content,
+
+    /// The SHA-1 checksum of the content (*`.sha1`*).
+    Checksum,
+
+    /// The PGP signature of the content (*`.asc`*).
+    Signature,
+}
+
+impl FileRole {
+    fn suffix(&self) -> &'static str {
+        match self {
+            Self::Content => "",
+            Self::Checksum => ".sha1",
+            Self::Signature => ".asc",
+        }
+    }
+}
+
+/// Optional files included by [`artifact_files`]. The jar and the pom are
+/// always included.
+#[derive(Debug, Clone, Default)]
+pub struct FileSetOptions {
+    /// Include the jar with the sources (*`-sources.jar`*).
+    pub sources: bool,
+
+    /// Include the jar with the javadoc (*`-javadoc.jar`*).
+    pub javadoc: bool,
+
+    /// Include the Gradle module metadata (*`.module`*).
+    pub module: bool,
+}
+
+/// A file expected for a version of an artifact, which knows where it's located both in
+/// a local repository and in a remote repository.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtifactFile {
+    pub artifact: Artifact,
+    pub kind: FileKind,
+    pub role: FileRole,
+}
+
+impl ArtifactFile {
+    pub fn new(artifact: &Artifact, kind: FileKind, role: FileRole) -> Self {
+        Self {
+            artifact: artifact.clone(),
+            kind,
+            role,
+        }
+    }
+
+    /// The file whose checksum or signature is this file (*or itself, if this
+    /// file is the content*).
+    pub fn content(&self) -> Self {
+        Self::new(&self.artifact, self.kind, FileRole::Content)
+    }
+
+    /// Location of the file in the given local repository
+    /// (*i.e. `group_id/artifact_id/version-sources.jar.sha1`*).
+    pub fn local_path(&self, repository: &Repository) -> PathBuf {
+        repository.artifact_as_dirname(&self.artifact).join(format!(
+            "{}{}{}",
+            self.artifact.version,
+            self.kind.suffix(),
+            self.role.suffix()
+        ))
+    }
+
+    /// Location of the file in the given remote repository. This method might fail
+    /// if the artifact contains unexpected characters that might not be able to be
+    /// represented in the URL.
+    pub fn remote_url(&self, remote_repository: &RemoteRepository) -> Result<Url, ParseError> {
+        let base_artifact_url = remote_repository.artifact_url(&self.artifact)?;
+        Url::parse(&format!(
+            "{base_artifact_url}{}{}",
+            self.kind.suffix(),
+            self.role.suffix()
+        ))
+    }
+}
+
+/// Enumerates every file expected for the given version of an artifact: its jar and pom,
+/// along with the optional files requested, each one followed by its checksum and its
+/// signature. The pom comes last, since its presence marks the artifact as present.
+///
+/// This is the single source of truth for the names of the files of an artifact, so any
+/// tool dealing with them (*i.e. deploying or verifying them*) should use it instead of
+/// building their paths or URLs.
+pub fn artifact_files(artifact: &Artifact, options: &FileSetOptions) -> Vec<ArtifactFile> {
+    let kinds = [
+        (FileKind::Jar, true),
+        (FileKind::Sources, options.sources),
+        (FileKind::Javadoc, options.javadoc),
+        (FileKind::Module, options.module),
+        (FileKind::Pom, true),
+    ];
+
+    kinds
+        .into_iter()
+        .filter(|(_, included)| *included)
+        .flat_map(|(kind, _)| {
+            [FileRole::Content, FileRole::Checksum, FileRole::Signature]
+                .into_iter()
+                .map(move |role| ArtifactFile::new(artifact, kind, role))
+        })
+        .collect()
+}
@@ -1,6 +1,14 @@
 pub mod repository;
 pub mod utils;
 pub mod error;
+pub mod files;
+pub mod mirror;
+pub mod operation;
+pub mod compression;
+pub mod deploy;
+pub mod diff;
+pub mod report;
+pub mod validation;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,327 @@
+use crate::error::RepositoryOperationError;
+use crate::operation::{OperationContext, OperationId, Progress};
+use crate::repository::{Artifact, RemoteRepository, Repository};
+use serde::Serialize;
+use std::fs::read_dir;
+
+/// Artifacts synchronized by a [`MirrorJob`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MirrorTarget {
+    /// Every artifact of the local repository belonging to the group, or any of
+    /// its subgroups (*i.e. `org.junit` includes `org.junit.jupiter`*). Artifacts
+    /// not present in the local repository aren't discovered.
+    Group(String),
+
+    /// An exact version of an artifact, which gets saved if it's missing.
+    Artifact(Artifact),
+}
+
+/// Job synchronizing the local repository against remote repositories: saving the
+/// new versions of the artifacts targeted, and updating their `maven-metadata.xml`.
+/// It's meant to be run periodically (*i.e. by cron or a CI pipeline*), reporting
+/// the changes made in a [`SyncReport`].
+#[derive(Default)]
+pub struct MirrorJob {
+    pub targets: Vec<MirrorTarget>,
+
+    /// Remote repositories to synchronize against, in order of preference. Each
+    /// file is fetched from the first remote repository that has it.
+    pub remotes: Vec<RemoteRepository>,
+
+    /// If enabled, only the metadata of the new versions gets saved, without their
+    /// jars (*see [`Repository::save_metadata_from_remote`]*).
+    pub metadata_only: bool,
+}
+
+/// Changes made to an artifact by a synchronization.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ArtifactSync {
+    pub group_id: String,
+    pub artifact_id: String,
+
+    /// Whether the `maven-metadata.xml` of the artifact was created or updated.
+    pub metadata_updated: bool,
+
+    /// Versions saved.
+    pub new_versions: Vec<String>,
+
+    /// Versions that couldn't be saved, along with the reason.
+    pub failures: Vec<SyncFailure>,
+}
+
+/// A version that couldn't be saved.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SyncFailure {
+    /// Version that couldn't be saved, or `None` if the failure affected
+    /// the whole artifact (*i.e. fetching its `maven-metadata.xml`*).
+    pub version: Option<String>,
+
+    /// Kind of the error (*see [`RepositoryOperationError::kind`]*), which can be
+    /// relied on, unlike the message.
+    pub kind: &'static str,
+
+    /// Message describing the error.
+    pub error: String,
+}
+
+impl SyncFailure {
+    fn new(version: Option<String>, error: &RepositoryOperationError) -> Self {
+        Self {
+            version,
+            kind: error.kind(),
+            error: error.to_string(),
+        }
+    }
+}
+
+/// Report of a synchronization, which can be serialized (*i.e. with [`Self::to_json`]*)
+/// for other tools to consume.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SyncReport {
+    /// Id of the operation (*see [`OperationContext`]*).
+    pub operation: OperationId,
+    pub artifacts: Vec<ArtifactSync>,
+}
+
+impl SyncReport {
+    /// Whether every artifact was synchronized without failures.
+    pub fn is_success(&self) -> bool {
+        self.artifacts
+            .iter()
+            .all(|artifact| artifact.failures.is_empty())
+    }
+
+    /// Serializes the report as pretty printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+impl MirrorJob {
+    /// Runs the synchronization. Failures saving a version or fetching the metadata of an
+    /// artifact don't stop the job, being recorded in the report instead. The job stops only
+    /// when it can't read the local repository, or if it gets cancelled or its deadline passes
+    /// (*checked before synchronizing each artifact*).
+    pub fn run(
+        &self,
+        repository: &Repository,
+        context: &OperationContext,
+    ) -> crate::RepositoryOperationResult<SyncReport> {
+        let artifacts = self.expand_targets(repository)?;
+        let mut report = SyncReport {
+            operation: context.id,
+            artifacts: Vec::new(),
+        };
+
+        for (i, (artifact, wanted_version)) in artifacts.iter().enumerate() {
+            context.check(|| Progress {
+                completed: artifacts[..i].iter().map(|(a, _)| a.clone()).collect(),
+                pending: artifacts[i..].iter().map(|(a, _)| a.clone()).collect(),
+            })?;
+            report.artifacts.push(self.sync_artifact(
+                repository,
+                artifact,
+                wanted_version.as_deref(),
+            ));
+        }
+
+        Ok(report)
+    }
+
+    /// Resolves the targets into the artifacts to synchronize (*without version*), along
+    /// with the version explicitly requested, if any.
+    fn expand_targets(
+        &self,
+        repository: &Repository,
+    ) -> std::io::Result<Vec<(Artifact, Option<String>)>> {
+        let mut artifacts: Vec<(Artifact, Option<String>)> = Vec::new();
+        for target in &self.targets {
+            match target {
+                MirrorTarget::Group(group) => {
+                    for artifact in local_artifacts(repository, group)? {
+                        if !artifacts.iter().any(|(a, _)| *a == artifact) {
+                            artifacts.push((artifact, None));
+                        }
+                    }
+                }
+                MirrorTarget::Artifact(artifact) => artifacts.push((
+                    Artifact::new(
+                        artifact.group_id.as_str(),
+                        artifact.artifact_id.as_str(),
+                        "",
+                    ),
+                    Some(artifact.version.clone()),
+                )),
+            }
+        }
+        Ok(artifacts)
+    }
+
+    fn sync_artifact(
+        &self,
+        repository: &Repository,
+        artifact: &Artifact,
+        wanted_version: Option<&str>,
+    ) -> ArtifactSync {
+        let mut sync = ArtifactSync {
+            group_id: artifact.group_id.clone(),
+            artifact_id: artifact.artifact_id.clone(),
+            metadata_updated: false,
+            new_versions: Vec::new(),
+            failures: Vec::new(),
+        };
+
+        let metadata = match self.fetch_metadata(artifact) {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                sync.failures.push(SyncFailure::new(None, &e));
+                None
+            }
+        };
+
+        let local_versions = repository
+            .get_artifact_available_versions(artifact)
+            .unwrap_or_default();
+        let versions = match (&metadata, wanted_version) {
+            (_, Some(version)) => vec![version.to_string()],
+            (Some(metadata), None) => {
+                match crate::utils::versions_in_metadata(String::from_utf8_lossy(metadata)) {
+                    Ok(versions) => new_versions(&versions, |v| local_versions.contains(v)),
+                    Err(e) => {
+                        sync.failures
+                            .push(SyncFailure::new(None, &RepositoryOperationError::from(e)));
+                        Vec::new()
+                    }
+                }
+            }
+            (None, None) => Vec::new(),
+        };
+
+        for version in versions {
+            let versioned = Artifact::new(&artifact.group_id, &artifact.artifact_id, &version);
+            if local_versions.contains(&version) {
+                continue;
+            }
+            match self.save_version(repository, &versioned) {
+                Ok(()) => sync.new_versions.push(version),
+                Err(e) => sync.failures.push(SyncFailure::new(Some(version), &e)),
+            }
+        }
+
+        if let Some(metadata) = metadata {
+            let stored = repository
+                .read_stored_file(repository.artifact_metadata_path(artifact))
+                .ok();
+            if stored.as_ref() != Some(&metadata) {
+                match repository.save_metadata(artifact, &metadata) {
+                    Ok(_) => sync.metadata_updated = true,
+                    Err(e) => sync
+                        .failures
+                        .push(SyncFailure::new(None, &RepositoryOperationError::from(e))),
+                }
+            }
+        }
+
+        sync
+    }
+
+    /// Fetches the `maven-metadata.xml` of the artifact from the first remote repository
+    /// that has it, verifying it against its checksum. If none of them has it, the error
+    /// of the last remote repository that failed gets returned (*if any*).
+    fn fetch_metadata(
+        &self,
+        artifact: &Artifact,
+    ) -> crate::RepositoryOperationResult<Option<Vec<u8>>> {
+        let fetch = |remote: &RemoteRepository| -> crate::RepositoryOperationResult<_> {
+            let metadata_url = remote.metadata_artifact_url(artifact)?.to_string();
+            let metadata = crate::repository::fetch_optional(&metadata_url)?;
+            if let Some(metadata) = &metadata {
+                crate::repository::verify_remote_checksum(&metadata_url, metadata)?;
+            }
+            Ok(metadata)
+        };
+
+        let mut last_error = None;
+        for remote in &self.remotes {
+            match fetch(remote) {
+                Ok(Some(metadata)) => return Ok(Some(metadata)),
+                Ok(None) => {}
+                Err(e) => last_error = Some(e),
+            }
+        }
+        match last_error {
+            Some(e) => Err(e),
+            None => Ok(None),
+        }
+    }
+
+    /// Saves the version from the first remote repository that has it.
+    fn save_version(
+        &self,
+        repository: &Repository,
+        artifact: &Artifact,
+    ) -> crate::RepositoryOperationResult<()> {
+        let mut last_error = None;
+        for remote in &self.remotes {
+            let saved = if self.metadata_only {
+                repository.save_metadata_from_remote(artifact, remote, &|_| {})
+            } else {
+                repository.save_from_remote(artifact, remote, &|_, _| {})
+            };
+            match saved {
+                Ok(_) => return Ok(()),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            RepositoryOperationError::IoError(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "there are no remote repositories to mirror from",
+            ))
+        }))
+    }
+}
+
+/// Versions listed in a `maven-metadata.xml` (*from the oldest to the newest*) that are
+/// newer than the newest version present locally. If none of them is present locally,
+/// only the newest one is considered new.
+fn new_versions<F>(versions: &[String], is_local: F) -> Vec<String>
+where
+    F: Fn(&String) -> bool,
+{
+    match versions.iter().rposition(is_local) {
+        Some(newest_local) => versions[newest_local + 1..].to_vec(),
+        None => versions.last().cloned().into_iter().collect(),
+    }
+}
+
+/// Artifacts (*without version*) of the local repository belonging to the group,
+/// or any of its subgroups.
+fn local_artifacts(repository: &Repository, group: &str) -> std::io::Result<Vec<Artifact>> {
+    let mut artifacts = Vec::new();
+    if !repository.base_path().exists() {
+        return Ok(artifacts);
+    }
+
+    for group_entry in read_dir(repository.base_path())? {
+        let group_entry = group_entry?;
+        let group_id = group_entry.file_name().to_string_lossy().to_string();
+        if !group_entry.path().is_dir() || !crate::utils::group_matches(group_id.as_str(), group) {
+            continue;
+        }
+
+        for artifact_entry in read_dir(group_entry.path())? {
+            let artifact_entry = artifact_entry?;
+            if artifact_entry.path().is_dir() {
+                artifacts.push(Artifact::new(
+                    group_id.clone(),
+                    artifact_entry.file_name().to_string_lossy().to_string(),
+                    String::new(),
+                ));
+            }
+        }
+    }
+
+    artifacts.sort_by(|a, b| (&a.group_id, &a.artifact_id).cmp(&(&b.group_id, &b.artifact_id)));
+    Ok(artifacts)
+}
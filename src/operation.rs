@@ -0,0 +1,144 @@
+use crate::error::RepositoryOperationError;
+use crate::repository::Artifact;
+use serde::Serialize;
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// Identifier of an operation, unique within the process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct OperationId(pub u64);
+
+impl OperationId {
+    /// Returns a new identifier, different from all of the previous ones.
+    pub fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl std::fmt::Display for OperationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "op-{}", self.0)
+    }
+}
+
+/// Progress made by an operation before being interrupted, which can be used
+/// to resume it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Progress {
+    /// Artifacts completely processed.
+    pub completed: Vec<Artifact>,
+
+    /// Artifacts known to be left, including the one being processed when the
+    /// operation got interrupted.
+    pub pending: Vec<Artifact>,
+}
+
+/// Handle used to cancel an operation, possibly from another thread. Clones
+/// of the token cancel the same operation.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the cancellation of the operation. The operation stops the next
+    /// time it checks the token (*i.e. between artifacts*).
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Context of a long running operation, used to identify it, cancel it or limit
+/// the time it can take.
+#[derive(Debug, Clone)]
+pub struct OperationContext {
+    pub id: OperationId,
+    pub cancellation: CancellationToken,
+
+    /// Instant after which the operation must stop. It gets checked between
+    /// artifacts, so the operation might take longer while a download finishes.
+    pub deadline: Option<Instant>,
+}
+
+impl Default for OperationContext {
+    fn default() -> Self {
+        Self {
+            id: OperationId::next(),
+            cancellation: CancellationToken::new(),
+            deadline: None,
+        }
+    }
+}
+
+impl OperationContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a context whose deadline is `timeout` from now.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            deadline: Some(Instant::now() + timeout),
+            ..Self::default()
+        }
+    }
+
+    /// Checks if the operation has been cancelled or its deadline has passed, returning
+    /// the corresponding error with the progress given if so.
+    pub(crate) fn check<F>(&self, progress: F) -> crate::RepositoryOperationResult<()>
+    where
+        F: FnOnce() -> Progress,
+    {
+        if self.cancellation.is_cancelled() {
+            Err(RepositoryOperationError::Cancelled {
+                operation: self.id,
+                progress: progress(),
+            })
+        } else if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            Err(RepositoryOperationError::DeadlineExceeded {
+                operation: self.id,
+                progress: progress(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Attaches the operation to the errors caused by a request timing out, turning them
+    /// into [`RepositoryOperationError::NetworkTimeout`]. Any other error is returned as is.
+    pub(crate) fn interrupted<F>(
+        &self,
+        error: RepositoryOperationError,
+        progress: F,
+    ) -> RepositoryOperationError
+    where
+        F: FnOnce() -> Progress,
+    {
+        match error {
+            RepositoryOperationError::GetError(e) if e.is_timeout() => {
+                RepositoryOperationError::NetworkTimeout {
+                    operation: self.id,
+                    url: e.url().map(|url| url.to_string()),
+                    progress: progress(),
+                }
+            }
+            error => error,
+        }
+    }
+}
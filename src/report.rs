@@ -0,0 +1,118 @@
+use crate::repository::Artifact;
+use std::{
+    ops::{Add, AddAssign},
+    time::{Duration, Instant},
+};
+
+/// Time spent in each of the phases of resolving an artifact.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Timings {
+    /// Time spent fetching the pom of the artifact.
+    pub metadata_fetch: Duration,
+
+    /// Time spent parsing the pom of the artifact (*i.e. looking for its dependencies*).
+    pub parse: Duration,
+
+    /// Time spent downloading the jar of the artifact.
+    pub download: Duration,
+
+    /// Time spent writing the artifact's files to the local repository.
+    pub disk_write: Duration,
+
+    /// Time spent fetching the remote checksums and verifying the downloaded files
+    /// against them (*as well as validating the pom, if the strict validation is enabled*).
+    pub verification: Duration,
+}
+
+impl Timings {
+    /// Sum of the time spent in every phase.
+    pub fn total(&self) -> Duration {
+        self.metadata_fetch + self.parse + self.download + self.disk_write + self.verification
+    }
+
+    /// Runs the given action, adding the time it took to the phase
+    /// returned by `phase`.
+    pub(crate) fn measure<T, F>(&mut self, phase: fn(&mut Self) -> &mut Duration, action: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let start = Instant::now();
+        let result = action();
+        *phase(self) += start.elapsed();
+        result
+    }
+}
+
+impl Add for Timings {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            metadata_fetch: self.metadata_fetch + rhs.metadata_fetch,
+            parse: self.parse + rhs.parse,
+            download: self.download + rhs.download,
+            disk_write: self.disk_write + rhs.disk_write,
+            verification: self.verification + rhs.verification,
+        }
+    }
+}
+
+impl AddAssign for Timings {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Timings of a single artifact resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtifactReport {
+    pub artifact: Artifact,
+    pub timings: Timings,
+}
+
+/// Report of a resolution, containing the artifacts resolved
+/// (*in the order they were resolved*) along with the time spent
+/// on each one of them.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ResolutionReport {
+    pub artifacts: Vec<ArtifactReport>,
+}
+
+impl ResolutionReport {
+    /// Sum of the timings of every artifact resolved.
+    pub fn aggregate(&self) -> Timings {
+        self.artifacts
+            .iter()
+            .fold(Timings::default(), |acc, report| acc + report.timings)
+    }
+}
+
+impl std::fmt::Display for ResolutionReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let write_timings = |f: &mut std::fmt::Formatter<'_>, name: &str, timings: &Timings| {
+            writeln!(
+                f,
+                "{name}: total {:?} (metadata fetch {:?}, parse {:?}, download {:?}, disk write {:?}, verification {:?})",
+                timings.total(),
+                timings.metadata_fetch,
+                timings.parse,
+                timings.download,
+                timings.disk_write,
+                timings.verification
+            )
+        };
+
+        for report in &self.artifacts {
+            let artifact = &report.artifact;
+            write_timings(
+                f,
+                &format!(
+                    "{}:{}:{}",
+                    artifact.group_id, artifact.artifact_id, artifact.version
+                ),
+                &report.timings,
+            )?;
+        }
+        write_timings(f, "aggregate", &self.aggregate())
+    }
+}
@@ -1,11 +1,15 @@
+use crate::compression::Compression;
+use crate::deploy::{DeployOptions, DeployReport};
+use crate::diff::ArtifactDiff;
 use crate::error::RepositoryOperationError;
-use reqwest::blocking::get;
+use crate::files::{ArtifactFile, FileKind, FileRole, FileSetOptions};
+use crate::operation::{OperationContext, Progress};
+use crate::report::{ArtifactReport, ResolutionReport, Timings};
 use serde::Deserialize;
 use std::{
-    collections::HashSet,
-    fs::{read_dir, File},
-    io::copy,
-    path::PathBuf,
+    collections::{HashMap, HashSet},
+    fs::read_dir,
+    path::{Path, PathBuf},
 };
 use url::{ParseError, Url};
 
@@ -36,6 +40,13 @@ impl Artifact {
 /// deleting artifacts.
 pub struct Repository {
     base_path: PathBuf,
+
+    /// Whether the poms fetched from remote repositories must be valid
+    /// according to the POM schema (*see [`crate::validation::validate_pom`]*).
+    strict_pom_validation: bool,
+
+    /// Compression of the poms and metadata files written to the repository.
+    compression: Compression,
 }
 
 impl Default for Repository {
@@ -45,47 +56,95 @@ impl Default for Repository {
         } else {
             std::env::var("HOME").unwrap_or_default()
         };
+        let base_path = PathBuf::from(home_directory).join("./repo");
         Self {
-            base_path: PathBuf::from(home_directory).join("./repo"),
+            compression: crate::compression::read_format_marker(&base_path),
+            base_path,
+            strict_pom_validation: false,
         }
     }
 }
 
 impl Repository {
+    /// Creates a repository located at `base_path`. If there's already a repository
+    /// there, its compression is read from its format marker
+    /// (*see [`crate::compression::FORMAT_MARKER`]*).
     pub fn new<T: Into<PathBuf>>(base_path: T) -> Self {
+        let base_path = base_path.into();
         Self {
-            base_path: base_path.into(),
+            compression: crate::compression::read_format_marker(&base_path),
+            base_path,
+            strict_pom_validation: false,
         }
     }
 
-    fn artifact_as_dirname(&self, artifact: &Artifact) -> PathBuf {
+    /// Sets the compression used for the poms, Gradle module metadata and
+    /// `maven-metadata.xml` files written from now on, recording it in the format
+    /// marker of the repository. The files already stored aren't recompressed, but
+    /// they can still be read, since reads detect the compression of each file.
+    pub fn set_compression(&mut self, compression: Compression) -> std::io::Result<()> {
+        crate::compression::write_format_marker(&self.base_path, compression)?;
+        self.compression = compression;
+        Ok(())
+    }
+
+    /// Compression used for the poms and metadata files written to the repository.
+    pub fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Reads a file stored in the repository, decompressing it if it was compressed
+    /// (*see [`Self::set_compression`]*).
+    pub fn read_stored_file<P: AsRef<Path>>(&self, path: P) -> std::io::Result<Vec<u8>> {
+        crate::compression::decompress(std::fs::read(path)?)
+    }
+
+    /// Reads the pom of the given artifact, decompressing it if needed.
+    pub fn read_pom(&self, artifact: &Artifact) -> std::io::Result<String> {
+        String::from_utf8(self.read_stored_file(self.artifact_pom_path(artifact))?)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Enables or disables the validation of the poms fetched from remote repositories
+    /// against the POM schema. When enabled, fetching a pom with schema violations results
+    /// in a [`RepositoryOperationError::PomSchemaViolation`], and the artifact doesn't get saved.
+    ///
+    /// Disabled by default.
+    pub fn set_strict_pom_validation(&mut self, strict_pom_validation: bool) {
+        self.strict_pom_validation = strict_pom_validation;
+    }
+
+    /// Whether the poms fetched from remote repositories get validated against the POM schema.
+    pub fn strict_pom_validation(&self) -> bool {
+        self.strict_pom_validation
+    }
+
+    pub(crate) fn artifact_as_dirname(&self, artifact: &Artifact) -> PathBuf {
         self.base_path
             .join(&artifact.group_id)
             .join(&artifact.artifact_id)
     }
 
+    /// Path of the jar of the artifact (*`group_id/artifact_id/version.jar`*).
     pub fn artifact_jar_path(&self, artifact: &Artifact) -> PathBuf {
-        let mut path = self.artifact_as_dirname(artifact).join(&artifact.version);
-        path.set_extension(
-            path.extension()
-                .unwrap_or_default()
-                .to_string_lossy()
-                .to_string()
-                + ".jar",
-        );
-        path
+        ArtifactFile::new(artifact, FileKind::Jar, FileRole::Content).local_path(self)
     }
 
+    /// Path of the pom of the artifact (*`group_id/artifact_id/version.pom`*).
     pub fn artifact_pom_path(&self, artifact: &Artifact) -> PathBuf {
-        let mut path = self.artifact_as_dirname(artifact).join(&artifact.version);
-        path.set_extension(
-            path.extension()
-                .unwrap_or_default()
-                .to_string_lossy()
-                .to_string()
-                + ".pom",
-        );
-        path
+        ArtifactFile::new(artifact, FileKind::Pom, FileRole::Content).local_path(self)
+    }
+
+    /// Path of the Gradle module metadata of the artifact (*`group_id/artifact_id/version.module`*).
+    pub fn artifact_module_path(&self, artifact: &Artifact) -> PathBuf {
+        ArtifactFile::new(artifact, FileKind::Module, FileRole::Content).local_path(self)
+    }
+
+    /// Path of the `maven-metadata.xml` of the artifact, which is shared by all of
+    /// its versions (*`group_id/artifact_id/maven-metadata.xml`*).
+    pub fn artifact_metadata_path(&self, artifact: &Artifact) -> PathBuf {
+        self.artifact_as_dirname(artifact)
+            .join("maven-metadata.xml")
     }
 
     /// Checks if the artifact exists.
@@ -93,6 +152,12 @@ impl Repository {
         self.artifact_pom_path(artifact).exists()
     }
 
+    /// Checks if the jar of the artifact exists, which might not be the case even if the
+    /// artifact exists (*i.e. if only its metadata was mirrored, see [`Self::mirror_metadata`]*).
+    pub fn has_jar(&self, artifact: &Artifact) -> bool {
+        self.artifact_jar_path(artifact).exists()
+    }
+
     /// Writes the jar's content to its correspondent file in the repository.
     ///
     /// Sample location of an artifact's jar: `group_id/artifact_id/version.xml`
@@ -105,9 +170,10 @@ impl Repository {
         let artifact_jar_path = self.artifact_jar_path(artifact);
 
         std::fs::create_dir_all(artifact_dirname)?;
-        copy(
-            &mut artifact_content.as_ref(),
-            &mut File::create(&artifact_jar_path)?,
+        Self::write_with_checksum(
+            &artifact_jar_path,
+            artifact_content.as_ref(),
+            Compression::None,
         )?;
         Ok(artifact_jar_path)
     }
@@ -124,25 +190,436 @@ impl Repository {
         let artifact_pom_path = self.artifact_pom_path(artifact);
 
         std::fs::create_dir_all(artifact_dirname)?;
-        copy(
-            &mut artifact_content.as_ref(),
-            &mut File::create(&artifact_pom_path)?,
+        Self::write_with_checksum(
+            &artifact_pom_path,
+            artifact_content.as_ref(),
+            self.compression,
         )?;
         Ok(artifact_pom_path)
     }
 
+    /// Writes the Gradle module metadata content to its correspondent file in the repository.
+    ///
+    /// Sample location of a module: *`group_id/artifact_id/version.module`*
+    pub fn save_module<T: AsRef<[u8]>>(
+        &self,
+        artifact: &Artifact,
+        module_content: T,
+    ) -> std::io::Result<PathBuf> {
+        let module_path = self.artifact_module_path(artifact);
+
+        std::fs::create_dir_all(self.artifact_as_dirname(artifact))?;
+        Self::write_with_checksum(&module_path, module_content.as_ref(), self.compression)?;
+        Ok(module_path)
+    }
+
+    /// Writes the `maven-metadata.xml` content of the artifact to its correspondent
+    /// file in the repository.
+    ///
+    /// Sample location of the metadata: *`group_id/artifact_id/maven-metadata.xml`*
+    pub fn save_metadata<T: AsRef<[u8]>>(
+        &self,
+        artifact: &Artifact,
+        metadata_content: T,
+    ) -> std::io::Result<PathBuf> {
+        let metadata_path = self.artifact_metadata_path(artifact);
+
+        std::fs::create_dir_all(self.artifact_as_dirname(artifact))?;
+        Self::write_with_checksum(&metadata_path, metadata_content.as_ref(), self.compression)?;
+        Ok(metadata_path)
+    }
+
+    /// Writes the given content to `path` with the given compression, as well as
+    /// its SHA-1 checksum next to it (*see [`crate::utils::checksum_path`]*). The
+    /// checksum is always computed from the uncompressed content.
+    ///
+    /// Both files replace the existing ones instead of overwriting them (*see
+    /// [`replace_file`]*), as they might be hard linked from a sibling repository
+    /// (*see [`Self::adopt_from`]*).
+    fn write_with_checksum(
+        path: &Path,
+        content: &[u8],
+        compression: Compression,
+    ) -> std::io::Result<()> {
+        replace_file(path, &compression.compress(content)?)?;
+        replace_file(
+            &crate::utils::checksum_path(path),
+            crate::utils::sha1_hex(content).as_bytes(),
+        )
+    }
+
+    /// Installs every jar and pom found in the given directory (*not recursively*) in the
+    /// repository, as a single operation: either all the files get installed, or none of them.
+    /// If the installation fails midway, the files already installed get rolled back, restoring
+    /// the files they replaced, and the directories created get removed.
+    ///
+    /// The coordinates of each artifact are read from its pom. Jars are paired with the pom
+    /// that has the same file stem (*i.e. `foo-1.0.jar` and `foo-1.0.pom`*), as are the
+    /// sources and javadoc jars once their classifier is stripped (*i.e. `foo-1.0-sources.jar`*),
+    /// which get installed next to the jar. Jars without a pom get their coordinates guessed
+    /// from their filename. Since a filename doesn't say
+    /// anything about the group id, the guess gets passed to `resolve_coordinates` along with
+    /// the path of the jar, which should return the final coordinates (*or `None` to abort the
+    /// installation*).
+    ///
+    /// # Parameters
+    ///
+    /// * path - Directory containing the jars and poms to install.
+    /// * resolve_coordinates - Action that gets called once for every artifact without a pom,
+    ///   being passed the path of one of its jars as first parameter, and the coordinates
+    ///   guessed from its filename as the second parameter.
+    ///
+    /// # Returns
+    ///
+    /// The artifacts installed.
+    pub fn install_dir<P, T>(
+        &self,
+        path: P,
+        resolve_coordinates: T,
+    ) -> crate::RepositoryOperationResult<Vec<Artifact>>
+    where
+        P: AsRef<Path>,
+        T: Fn(&Path, Artifact) -> Option<Artifact>,
+    {
+        // Files of each artifact, by the file stem they share (*i.e. `foo-1.0` for both
+        // `foo-1.0.pom` and `foo-1.0-sources.jar`*).
+        let mut files_by_stem: HashMap<String, HashMap<FileKind, PathBuf>> = HashMap::new();
+
+        for entry in read_dir(path)? {
+            let entry_path = entry?.path();
+            if !entry_path.is_file() {
+                continue;
+            }
+            let file_stem = entry_path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            let (file_stem, kind) = match entry_path.extension().and_then(|ext| ext.to_str()) {
+                Some("pom") => (file_stem, FileKind::Pom),
+                Some("jar") => {
+                    if let Some(stem) = file_stem.strip_suffix("-sources") {
+                        (stem.to_string(), FileKind::Sources)
+                    } else if let Some(stem) = file_stem.strip_suffix("-javadoc") {
+                        (stem.to_string(), FileKind::Javadoc)
+                    } else {
+                        (file_stem, FileKind::Jar)
+                    }
+                }
+                _ => continue,
+            };
+            files_by_stem
+                .entry(file_stem)
+                .or_default()
+                .insert(kind, entry_path);
+        }
+
+        // Resolve the coordinates of everything before touching the repository.
+        let mut installation: Vec<(Artifact, HashMap<FileKind, PathBuf>)> = Vec::new();
+        for (file_stem, files) in files_by_stem {
+            let artifact = match files.get(&FileKind::Pom) {
+                Some(pom_path) => {
+                    let pom = std::fs::read_to_string(pom_path)?;
+                    crate::utils::artifact_in_pom(pom)?.ok_or_else(|| {
+                        RepositoryOperationError::UnresolvedCoordinates(pom_path.clone())
+                    })?
+                }
+                None => {
+                    let jar_path = [FileKind::Jar, FileKind::Sources, FileKind::Javadoc]
+                        .iter()
+                        .find_map(|kind| files.get(kind))
+                        .expect("every file stem has at least one file");
+                    resolve_coordinates(jar_path, crate::utils::artifact_from_filename(&file_stem))
+                        .ok_or_else(|| {
+                            RepositoryOperationError::UnresolvedCoordinates(jar_path.clone())
+                        })?
+                }
+            };
+            installation.push((artifact, files));
+        }
+
+        for (i, (artifact, _)) in installation.iter().enumerate() {
+            if installation[..i].iter().any(|(other, _)| other == artifact) {
+                return Err(RepositoryOperationError::DuplicateArtifact(
+                    artifact.clone(),
+                ));
+            }
+        }
+
+        // Copy every file next to its destination, and only move them to their
+        // final location once all of them have been copied.
+        let mut staged: Vec<(PathBuf, PathBuf)> = Vec::new();
+        let mut created_dirs: Vec<PathBuf> = Vec::new();
+        let staging_result = installation.iter().try_for_each(|(artifact, files)| {
+            let artifact_dir = self.artifact_as_dirname(artifact);
+            created_dirs.extend(
+                artifact_dir
+                    .ancestors()
+                    .take_while(|dir| !dir.exists())
+                    .map(Path::to_path_buf),
+            );
+            std::fs::create_dir_all(&artifact_dir)?;
+
+            for (kind, source) in files {
+                let destination =
+                    ArtifactFile::new(artifact, *kind, FileRole::Content).local_path(self);
+                let compression = match kind {
+                    FileKind::Pom => self.compression,
+                    _ => Compression::None,
+                };
+                let staging_path = suffixed_path(&destination, ".install");
+
+                // Register the staged files before writing them, so they get
+                // cleaned up even if the write fails midway.
+                staged.push((staging_path.clone(), destination.clone()));
+                staged.push((
+                    crate::utils::checksum_path(&staging_path),
+                    crate::utils::checksum_path(&destination),
+                ));
+                Self::write_with_checksum(&staging_path, &std::fs::read(source)?, compression)?;
+            }
+            Ok::<(), std::io::Error>(())
+        });
+
+        // Move the staged files to their final location, keeping the files they
+        // replace aside until all of them have been moved, so they can be restored.
+        let mut moved: Vec<(PathBuf, Option<PathBuf>)> = Vec::new();
+        let install_result = staging_result.and_then(|_| {
+            staged.iter().try_for_each(|(staging_path, destination)| {
+                let replaced = if destination.exists() {
+                    let replaced_path = suffixed_path(destination, ".replaced");
+                    std::fs::rename(destination, &replaced_path)?;
+                    Some(replaced_path)
+                } else {
+                    None
+                };
+                moved.push((destination.clone(), replaced));
+                std::fs::rename(staging_path, destination)
+            })
+        });
+
+        if let Err(e) = install_result {
+            Self::rollback_installation(&staged, &moved, created_dirs);
+            return Err(e.into());
+        }
+
+        moved.iter().for_each(|(_, replaced)| {
+            if let Some(replaced) = replaced {
+                let _ = std::fs::remove_file(replaced);
+            }
+        });
+
+        Ok(installation
+            .into_iter()
+            .map(|(artifact, _)| artifact)
+            .collect())
+    }
+
+    /// Undoes a failed installation (*see [`Self::install_dir`]*), on a best effort basis:
+    /// the files moved get replaced back by the files they replaced (*if any*), and the
+    /// staged files and the directories created get removed.
+    fn rollback_installation(
+        staged: &[(PathBuf, PathBuf)],
+        moved: &[(PathBuf, Option<PathBuf>)],
+        mut created_dirs: Vec<PathBuf>,
+    ) {
+        for (destination, replaced) in moved.iter().rev() {
+            let _ = std::fs::remove_file(destination);
+            if let Some(replaced) = replaced {
+                let _ = std::fs::rename(replaced, destination);
+            }
+        }
+        for (staging_path, _) in staged {
+            let _ = std::fs::remove_file(staging_path);
+        }
+
+        // Innermost directories first, so they're empty when removed.
+        created_dirs.sort_by_key(|dir| std::cmp::Reverse(dir.components().count()));
+        for dir in created_dirs {
+            let _ = std::fs::remove_dir(dir);
+        }
+    }
+
+    /// Compares the stored jars of two versions of the same artifact, listing the
+    /// entries added, removed and changed, as well as the changes in the main
+    /// attributes of their manifests.
+    ///
+    /// # Parameters
+    ///
+    /// * old - Version to compare from.
+    /// * new - Version to compare to, which must have the same group id
+    ///   and artifact id as `old`.
+    pub fn diff_artifacts(
+        &self,
+        old: &Artifact,
+        new: &Artifact,
+    ) -> crate::RepositoryOperationResult<ArtifactDiff> {
+        if old.group_id != new.group_id || old.artifact_id != new.artifact_id {
+            return Err(RepositoryOperationError::MismatchedArtifacts(
+                Box::new(old.clone()),
+                Box::new(new.clone()),
+            ));
+        }
+
+        crate::diff::diff_jars(
+            old,
+            &self.artifact_jar_path(old),
+            new,
+            &self.artifact_jar_path(new),
+        )
+    }
+
+    /// Deploys the artifact to the given remote repository, uploading its jar (*if
+    /// there's one*), its Gradle module metadata (*if there's one*) and its pom, along
+    /// with their SHA-1 checksums. The pom gets uploaded after the rest of the files,
+    /// followed by the `maven-metadata.xml` of the remote repository with the version
+    /// added to it (*see [`crate::utils::metadata_with_version`]*), so the version can
+    /// be discovered (*i.e. by a [`crate::mirror::MirrorJob`]*).
+    ///
+    /// Release versions are immutable, so if the pom of the artifact is already in the
+    /// remote repository the deployment fails with a
+    /// [`RepositoryOperationError::VersionAlreadyDeployed`] before uploading anything
+    /// (*even in a dry run*), as it does if the remote repository fails to answer whether
+    /// the pom is there (*i.e. rejecting the credentials*). Snapshot versions can always
+    /// be redeployed.
+    ///
+    /// # Returns
+    ///
+    /// A report containing the files uploaded and where they were uploaded to (*or
+    /// would be uploaded to, if `options.dry_run` is enabled*).
+    pub fn deploy_to_remote(
+        &self,
+        artifact: &Artifact,
+        remote_repository: &RemoteRepository,
+        options: &DeployOptions,
+    ) -> crate::RepositoryOperationResult<DeployReport> {
+        crate::deploy::deploy(self, artifact, remote_repository, options)
+    }
+
+    /// Adopts the files of the artifact from a sibling repository (*i.e. a shared
+    /// repository on the same machine*), hard linking them instead of downloading them
+    /// again. Falls back to copying the files if they can't be hard linked (*i.e. the
+    /// repositories are on different filesystems*).
+    ///
+    /// Every file gets verified against the SHA-1 checksum stored next to it in the sibling
+    /// repository before adopting anything, so either all the files get adopted, or none of
+    /// them. The pom is required, while the rest of the files (*see
+    /// [`crate::files::artifact_files`]*) are adopted only if the sibling repository has them.
+    /// If the sibling repository doesn't have the artifact, the adoption fails with a
+    /// [`RepositoryOperationError::ArtifactNotFound`].
+    ///
+    /// Since checksums are required, nothing can be adopted from repositories written before
+    /// the checksums were stored next to the files, as the adoption fails with a
+    /// [`RepositoryOperationError::MissingChecksum`] (*the artifact has to be downloaded
+    /// again instead*).
+    ///
+    /// The files are adopted as they are stored in the sibling repository, so they keep
+    /// their compression (*see [`Self::set_compression`]*).
+    ///
+    /// # Returns
+    ///
+    /// The paths of the files adopted, including their checksums.
+    pub fn adopt_from(
+        &self,
+        other: &Repository,
+        artifact: &Artifact,
+    ) -> crate::RepositoryOperationResult<Vec<PathBuf>> {
+        let files = crate::files::artifact_files(
+            artifact,
+            &FileSetOptions {
+                sources: true,
+                javadoc: true,
+                module: true,
+            },
+        );
+
+        let mut adoption: Vec<(PathBuf, PathBuf)> = Vec::new();
+        let mut adopting = false;
+        for file in files {
+            let source = file.local_path(other);
+            let destination = file.local_path(self);
+
+            match file.role {
+                FileRole::Content => {
+                    if file.kind == FileKind::Pom && !source.exists() {
+                        return Err(RepositoryOperationError::ArtifactNotFound(artifact.clone()));
+                    }
+                    adopting = source.exists();
+                    if !adopting {
+                        continue;
+                    }
+
+                    let checksum_path = crate::utils::checksum_path(&source);
+                    if !checksum_path.exists() {
+                        return Err(RepositoryOperationError::MissingChecksum(source));
+                    }
+                    let expected = std::fs::read_to_string(&checksum_path)?
+                        .trim()
+                        .to_lowercase();
+                    let actual = crate::utils::sha1_hex(other.read_stored_file(&source)?);
+                    if expected != actual {
+                        return Err(RepositoryOperationError::ChecksumMismatch {
+                            file: checksum_path.to_string_lossy().to_string(),
+                            expected,
+                            actual,
+                        });
+                    }
+                }
+                FileRole::Checksum => {}
+                FileRole::Signature => {
+                    if !source.exists() {
+                        continue;
+                    }
+                }
+            }
+
+            if adopting {
+                adoption.push((source, destination));
+            }
+        }
+
+        std::fs::create_dir_all(self.artifact_as_dirname(artifact))?;
+        for (source, destination) in &adoption {
+            // Link next to the destination first, so an existing file gets
+            // replaced in a single step.
+            let adopting_path = suffixed_path(destination, ".adopt");
+
+            let _ = std::fs::remove_file(&adopting_path);
+            if std::fs::hard_link(source, &adopting_path).is_err() {
+                std::fs::copy(source, &adopting_path)?;
+            }
+            std::fs::rename(&adopting_path, destination)?;
+        }
+
+        Ok(adoption
+            .into_iter()
+            .map(|(_, destination)| destination)
+            .collect())
+    }
+
     /// Returns an immutable reference to the path where the repository is located at.
     pub fn base_path(&self) -> &PathBuf {
         &self.base_path
     }
 
-    /// Returns a collection of stirngs
+    /// Returns a collection of stirngs, containing the versions of the given artifact
+    /// that have either a jar or a pom in the repository (*sources and javadoc jars
+    /// are ignored*).
     pub fn get_artifact_available_versions(&self, artifact: &Artifact) -> Option<HashSet<String>> {
         Some(
             read_dir(self.artifact_as_dirname(artifact))
                 .ok()?
                 .into_iter()
                 .filter_map(|element| element.ok())
+                .filter(|element| {
+                    let path = element.path();
+                    let file_stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                    matches!(
+                        path.extension().and_then(|ext| ext.to_str()),
+                        Some("jar" | "pom")
+                    ) && !file_stem.ends_with("-sources")
+                        && !file_stem.ends_with("-javadoc")
+                })
                 .map(|element| {
                     element
                         .path()
@@ -157,7 +634,9 @@ impl Repository {
 
     /// Recursive saves the specified artifact, as well as
     /// its dependencies in the local repository, using the remote
-    /// repository given.
+    /// repository given. Dependencies whose jar is already in the repository
+    /// are skipped (*but not the ones with only their metadata, see
+    /// [`Self::mirror_metadata`]*).
     ///
     /// # Parameters
     ///
@@ -168,31 +647,94 @@ impl Repository {
     /// being passed the pom's url as first parameter, and the jar's url as the
     /// second parameter.
     ///
+    /// # Returns
+    ///
+    /// A report with the time spent on each one of the artifacts saved.
+    ///
     /// # See
     /// * [`Self::save_from_remote`]
+    /// * [`Self::recursive_save_from_remote_with_context`]
     ///
     pub fn recursive_save_from_remote<T>(
         &self,
         artifact: &Artifact,
         remote_repository: &RemoteRepository,
         action_per_download: T,
-    ) -> crate::RepositoryOperationResult<()>
+    ) -> crate::RepositoryOperationResult<ResolutionReport>
     where
         T: Fn(String, String) -> (),
     {
+        self.recursive_save_from_remote_with_context(
+            artifact,
+            remote_repository,
+            &OperationContext::default(),
+            action_per_download,
+        )
+    }
+
+    /// Same as [`Self::recursive_save_from_remote`], but the operation can be cancelled or
+    /// limited in time through the given context. Both the cancellation and the deadline get
+    /// checked before saving each artifact.
+    ///
+    /// When interrupted, the error returned contains the id of the operation along with its
+    /// progress (*see [`RepositoryOperationError::progress`]*):
+    ///
+    /// * [`RepositoryOperationError::Cancelled`] if it was cancelled.
+    /// * [`RepositoryOperationError::DeadlineExceeded`] if its deadline passed.
+    /// * [`RepositoryOperationError::NetworkTimeout`] if a request to the remote
+    ///   repository timed out.
+    pub fn recursive_save_from_remote_with_context<T>(
+        &self,
+        artifact: &Artifact,
+        remote_repository: &RemoteRepository,
+        context: &OperationContext,
+        action_per_download: T,
+    ) -> crate::RepositoryOperationResult<ResolutionReport>
+    where
+        T: Fn(String, String),
+    {
+        let mut report = ResolutionReport::default();
         let mut artifact_list: Vec<Artifact> = vec![artifact.clone()];
         while let Some(dep) = artifact_list.pop() {
-            self.save_from_remote(&dep, remote_repository, &action_per_download)?;
+            let progress = || Progress {
+                completed: report
+                    .artifacts
+                    .iter()
+                    .map(|saved| saved.artifact.clone())
+                    .collect(),
+                pending: artifact_list
+                    .iter()
+                    .chain(std::iter::once(&dep))
+                    .cloned()
+                    .collect(),
+            };
+            context.check(progress)?;
 
-            let artifact_pom = std::fs::read_to_string(self.artifact_pom_path(artifact))?;
-            artifact_list.append(&mut crate::utils::dependencies_in_pom(artifact_pom)?);
+            let mut timings = self
+                .save_from_remote(&dep, remote_repository, &action_per_download)
+                .map_err(|e| context.interrupted(e, progress))?;
+
+            let mut dependencies = timings.measure(
+                |t| &mut t.parse,
+                || -> crate::RepositoryOperationResult<Vec<Artifact>> {
+                    let pom = self.read_pom(&dep)?;
+                    Ok(crate::utils::dependencies_in_pom(pom)?)
+                },
+            )?;
+            report.artifacts.push(ArtifactReport {
+                artifact: dep,
+                timings,
+            });
+
+            // Artifacts whose metadata was mirrored are still missing their jar.
+            artifact_list.append(&mut dependencies);
             artifact_list = artifact_list
                 .into_iter()
-                .filter(|a| !self.exists(a))
+                .filter(|a| !self.has_jar(a))
                 .collect();
         }
 
-        Ok(())
+        Ok(report)
     }
 
     /// Saves a given artifact to the local repository, downloading it from the
@@ -208,6 +750,16 @@ impl Repository {
     /// being passed the pom's url as first parameter, and the jar's url as the
     /// second parameter.
     ///
+    /// Both the pom and the jar get verified against the SHA-1 checksums published
+    /// by the remote repository (*if there are any*) before being saved, as well as
+    /// the pom against the POM schema if the strict validation is enabled
+    /// (*see [`Self::set_strict_pom_validation`]*).
+    ///
+    /// # Returns
+    ///
+    /// The time spent on each phase of the download (*the parse time is always zero,
+    /// since the pom doesn't get parsed*).
+    ///
     /// # See
     /// * [`Artifact::recursive_save_from_remote`]
     pub fn save_from_remote<T>(
@@ -215,23 +767,290 @@ impl Repository {
         artifact: &Artifact,
         remote_repository: &RemoteRepository,
         action_per_download: &T,
-    ) -> Result<(), RepositoryOperationError>
+    ) -> Result<Timings, RepositoryOperationError>
     where
         T: Fn(String, String) -> (),
     {
         // TODO: Check if the artifact already exists in the local
         // repository.
+        let mut timings = Timings::default();
         let pom_url = remote_repository.pom_artifact_url(artifact)?.to_string();
         let jar_url = remote_repository.jar_artifact_url(artifact)?.to_string();
 
         action_per_download(pom_url.to_string(), jar_url.to_string());
 
-        let pom_response = reqwest::blocking::get(pom_url)?;
-        let jar_response = reqwest::blocking::get(jar_url)?;
+        let pom = timings.measure(
+            |t| &mut t.metadata_fetch,
+            || {
+                reqwest::blocking::get(&pom_url)?
+                    .error_for_status()?
+                    .bytes()
+            },
+        )?;
+        let jar = timings.measure(
+            |t| &mut t.download,
+            || {
+                reqwest::blocking::get(&jar_url)?
+                    .error_for_status()?
+                    .bytes()
+            },
+        )?;
+
+        timings.measure(
+            |t| &mut t.verification,
+            || -> crate::RepositoryOperationResult<()> {
+                verify_remote_checksum(&pom_url, &pom)?;
+                verify_remote_checksum(&jar_url, &jar)?;
+                self.validate_fetched_pom(artifact, &pom)
+            },
+        )?;
+
+        timings.measure(
+            |t| &mut t.disk_write,
+            || -> std::io::Result<()> {
+                self.save_artifact(artifact, &jar)?;
+                self.save_pom(artifact, &pom)?;
+                Ok(())
+            },
+        )?;
+        Ok(timings)
+    }
+
+    /// Recursively saves only the metadata (*poms, Gradle module metadata and
+    /// `maven-metadata.xml` files*) of the specified artifacts and their dependencies,
+    /// without downloading any jar. This is meant for lightweight mirrors used for
+    /// dependency analysis.
+    ///
+    /// Only the artifacts belonging to the given groups (*or any of their subgroups,
+    /// i.e. `org.junit` includes `org.junit.jupiter`*) get saved, and the dependencies
+    /// of the artifacts outside of them aren't followed. Artifacts whose pom is already
+    /// in the repository are skipped.
+    ///
+    /// # Parameters
+    ///
+    /// * artifacts - Artifacts to save.
+    /// * groups - Group ids of the artifacts to mirror.
+    /// * remote_repository - The remote repository from where to
+    ///   download the metadata.
+    /// * action_per_download - Action that gets called before every download,
+    ///   being passed the pom's url.
+    ///
+    /// # Returns
+    ///
+    /// A report with the time spent on each one of the artifacts saved.
+    ///
+    /// # See
+    /// * [`Self::save_metadata_from_remote`]
+    pub fn mirror_metadata<T>(
+        &self,
+        artifacts: &[Artifact],
+        groups: &[String],
+        remote_repository: &RemoteRepository,
+        action_per_download: T,
+    ) -> crate::RepositoryOperationResult<ResolutionReport>
+    where
+        T: Fn(String),
+    {
+        let in_groups = |artifact: &Artifact| {
+            groups
+                .iter()
+                .any(|group| crate::utils::group_matches(&artifact.group_id, group))
+        };
+        let mut report = ResolutionReport::default();
+        let mut artifact_list: Vec<Artifact> = artifacts
+            .iter()
+            .filter(|artifact| in_groups(artifact) && !self.exists(artifact))
+            .cloned()
+            .collect();
+
+        while let Some(dep) = artifact_list.pop() {
+            if self.exists(&dep) {
+                continue;
+            }
+            let mut timings =
+                self.save_metadata_from_remote(&dep, remote_repository, &action_per_download)?;
+
+            let dependencies = timings.measure(
+                |t| &mut t.parse,
+                || -> crate::RepositoryOperationResult<Vec<Artifact>> {
+                    let pom = self.read_pom(&dep)?;
+                    Ok(crate::utils::dependencies_in_pom(pom)?)
+                },
+            )?;
+            report.artifacts.push(ArtifactReport {
+                artifact: dep,
+                timings,
+            });
+
+            artifact_list.extend(
+                dependencies
+                    .into_iter()
+                    .filter(|a| in_groups(a) && !self.exists(a)),
+            );
+        }
+
+        Ok(report)
+    }
+
+    /// Saves the metadata of the given artifact to the local repository, downloading it
+    /// from the specified remote repository: its pom, its Gradle module metadata and the
+    /// `maven-metadata.xml` of the artifact. Only the pom is required to be in the remote
+    /// repository, the rest of the files get saved only if the remote repository has them.
+    ///
+    /// Every file gets verified against the SHA-1 checksums published by the remote
+    /// repository (*if there are any*), as well as the pom against the POM schema if the
+    /// strict validation is enabled.
+    ///
+    /// # Returns
+    ///
+    /// The time spent on each phase of the download (*the parse and download times are
+    /// always zero, since the pom doesn't get parsed and there's no jar to download*).
+    pub fn save_metadata_from_remote<T>(
+        &self,
+        artifact: &Artifact,
+        remote_repository: &RemoteRepository,
+        action_per_download: &T,
+    ) -> crate::RepositoryOperationResult<Timings>
+    where
+        T: Fn(String),
+    {
+        let mut timings = Timings::default();
+        let pom_url = remote_repository.pom_artifact_url(artifact)?.to_string();
+        let module_url = remote_repository.module_artifact_url(artifact)?.to_string();
+        let metadata_url = remote_repository
+            .metadata_artifact_url(artifact)?
+            .to_string();
+
+        action_per_download(pom_url.to_string());
+
+        let (pom, module, metadata) = timings.measure(
+            |t| &mut t.metadata_fetch,
+            || -> reqwest::Result<_> {
+                Ok((
+                    reqwest::blocking::get(&pom_url)?
+                        .error_for_status()?
+                        .bytes()?,
+                    fetch_optional(&module_url)?,
+                    fetch_optional(&metadata_url)?,
+                ))
+            },
+        )?;
+
+        timings.measure(
+            |t| &mut t.verification,
+            || -> crate::RepositoryOperationResult<()> {
+                verify_remote_checksum(&pom_url, &pom)?;
+                if let Some(module) = &module {
+                    verify_remote_checksum(&module_url, module)?;
+                }
+                if let Some(metadata) = &metadata {
+                    verify_remote_checksum(&metadata_url, metadata)?;
+                }
+                self.validate_fetched_pom(artifact, &pom)
+            },
+        )?;
+
+        timings.measure(
+            |t| &mut t.disk_write,
+            || -> std::io::Result<()> {
+                if let Some(module) = &module {
+                    self.save_module(artifact, module)?;
+                }
+                if let Some(metadata) = &metadata {
+                    self.save_metadata(artifact, metadata)?;
+                }
+                self.save_pom(artifact, &pom)?;
+                Ok(())
+            },
+        )?;
+        Ok(timings)
+    }
+
+    /// Validates the pom fetched against the POM schema, only if the strict
+    /// validation is enabled.
+    fn validate_fetched_pom(
+        &self,
+        artifact: &Artifact,
+        pom: &[u8],
+    ) -> crate::RepositoryOperationResult<()> {
+        if !self.strict_pom_validation {
+            return Ok(());
+        }
+
+        let diagnostics = crate::validation::validate_pom(String::from_utf8_lossy(pom));
+        if diagnostics.is_empty() {
+            Ok(())
+        } else {
+            Err(RepositoryOperationError::PomSchemaViolation {
+                artifact: artifact.clone(),
+                diagnostics,
+            })
+        }
+    }
+}
+
+/// Path of a file located next to the given one, named after it plus `suffix`
+/// (*i.e. `1.0.0.jar` and `.install` result in `1.0.0.jar.install`*).
+fn suffixed_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut suffixed_path = path.as_os_str().to_owned();
+    suffixed_path.push(suffix);
+    PathBuf::from(suffixed_path)
+}
+
+/// Writes the given content next to `path` first, and then renames it over `path`, so an
+/// existing file gets replaced by a new one instead of being truncated (*which would
+/// also change every hard link to it*).
+fn replace_file(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let replacing_path = suffixed_path(path, ".tmp");
+
+    if let Err(e) = std::fs::write(&replacing_path, content)
+        .and_then(|_| std::fs::rename(&replacing_path, path))
+    {
+        let _ = std::fs::remove_file(&replacing_path);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Fetches the given file, returning `None` if the remote repository doesn't have it.
+pub(crate) fn fetch_optional(file_url: &str) -> reqwest::Result<Option<Vec<u8>>> {
+    let response = reqwest::blocking::get(file_url)?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    Ok(Some(response.error_for_status()?.bytes()?.to_vec()))
+}
+
+/// Verifies the given contents against the SHA-1 checksum published
+/// next to them in the remote repository (*`file_url` + `.sha1`*). If the remote
+/// repository doesn't publish a checksum for the file, the verification is skipped.
+pub(crate) fn verify_remote_checksum(
+    file_url: &str,
+    contents: &[u8],
+) -> crate::RepositoryOperationResult<()> {
+    let checksum_url = format!("{file_url}.sha1");
+    let response = reqwest::blocking::get(&checksum_url)?;
+    if !response.status().is_success() {
+        return Ok(());
+    }
+
+    // Some repositories append the name of the file after the checksum.
+    let expected = response
+        .text()?
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let actual = crate::utils::sha1_hex(contents);
 
-        self.save_artifact(artifact, jar_response.bytes()?)?;
-        self.save_pom(artifact, pom_response.bytes()?)?;
+    if expected == actual {
         Ok(())
+    } else {
+        Err(RepositoryOperationError::ChecksumMismatch {
+            file: checksum_url,
+            expected,
+            actual,
+        })
     }
 }
 
@@ -254,10 +1073,9 @@ impl Default for RemoteRepository {
 }
 
 impl RemoteRepository {
-    /// Base URL of the artifact (*it doesn't contain the '.jar', '.xml' etc... extension
-    /// of the file to download*).
-    pub fn artifact_url(&self, artifact: &Artifact) -> Result<Url, ParseError> {
-        //self.remote_url
+    /// URL of the directory containing every version of the artifact
+    /// (*i.e. `maven2/org/junit/jupiter/junit-jupiter-api`*).
+    fn artifact_dir_url(&self, artifact: &Artifact) -> Result<Url, ParseError> {
         let segmented_group_id = artifact.group_id.split(".");
         let mut remote_url = self.remote_url.clone();
 
@@ -272,26 +1090,56 @@ impl RemoteRepository {
         remote_url
             .path_segments_mut()
             .unwrap()
-            .push(artifact.artifact_id.as_str())
+            .push(artifact.artifact_id.as_str());
+
+        Ok(remote_url)
+    }
+
+    /// Base URL of the artifact (*it doesn't contain the '.jar', '.xml' etc... extension
+    /// of the file to download*).
+    pub fn artifact_url(&self, artifact: &Artifact) -> Result<Url, ParseError> {
+        let mut remote_url = self.artifact_dir_url(artifact)?;
+
+        remote_url
+            .path_segments_mut()
+            .unwrap()
             .push(artifact.version.as_str())
             .push(format!("{}-{}", artifact.artifact_id, artifact.version).as_str());
 
         Ok(remote_url)
     }
 
+    /// Generates the URL of the given artifact's Gradle module metadata. This method
+    /// might fail if the passed artifact contains unexpected characters that might not
+    /// be able to be represented in the URL.
+    pub fn module_artifact_url(&self, artifact: &Artifact) -> Result<Url, ParseError> {
+        ArtifactFile::new(artifact, FileKind::Module, FileRole::Content).remote_url(self)
+    }
+
+    /// Generates the URL of the `maven-metadata.xml` of the given artifact, which lists
+    /// all of its versions (*the version of the artifact is ignored*). This method might
+    /// fail if the passed artifact contains unexpected characters that might not be able
+    /// to be represented in the URL.
+    pub fn metadata_artifact_url(&self, artifact: &Artifact) -> Result<Url, ParseError> {
+        let mut remote_url = self.artifact_dir_url(artifact)?;
+        remote_url
+            .path_segments_mut()
+            .unwrap()
+            .push("maven-metadata.xml");
+        Ok(remote_url)
+    }
+
     /// Generates the URL of the given artifact's jar. This method might fail
     /// if the passed artifact contains unexpected characters that might not
     /// be able to be represented in the URL.
     pub fn jar_artifact_url(&self, artifact: &Artifact) -> Result<Url, ParseError> {
-        let base_artifact_url = self.artifact_url(artifact)?;
-        Url::parse(&(base_artifact_url.to_string() + ".jar"))
+        ArtifactFile::new(artifact, FileKind::Jar, FileRole::Content).remote_url(self)
     }
 
     /// Generates the URL of the given artifact's pom. This method might fail
     /// if the passed artifact contains unexpected characters that might not
     /// be able to be represented in the URL.
     pub fn pom_artifact_url(&self, artifact: &Artifact) -> Result<Url, ParseError> {
-        let base_artifact_url = self.artifact_url(artifact)?;
-        Url::parse(&(base_artifact_url.to_string() + ".pom"))
+        ArtifactFile::new(artifact, FileKind::Pom, FileRole::Content).remote_url(self)
     }
 }
@@ -0,0 +1,58 @@
+use super::create_temp_repository;
+use crate::compression::Compression;
+use crate::repository::{Artifact, Repository};
+
+const POM: &str = "<project><artifactId>artifact</artifactId></project>";
+
+#[test]
+fn compressed_pom() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, mut repo) = create_temp_repository()?;
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+
+    repo.set_compression(Compression::Zstd)?;
+    repo.save_pom(&artifact, POM)?;
+    repo.save_artifact(&artifact, "jar")?;
+
+    assert_ne!(
+        POM.as_bytes(),
+        std::fs::read(repo.artifact_pom_path(&artifact))?
+    );
+    assert_eq!(POM, repo.read_pom(&artifact)?);
+    assert_eq!(
+        "jar",
+        std::fs::read_to_string(repo.artifact_jar_path(&artifact))?
+    );
+    assert_eq!(
+        crate::utils::sha1_hex(POM),
+        std::fs::read_to_string(crate::utils::checksum_path(
+            repo.artifact_pom_path(&artifact)
+        ))?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn compression_recorded_in_marker() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, mut repo) = create_temp_repository()?;
+    let compressed = Artifact::new("group", "artifact", "1.0.0");
+    let uncompressed = Artifact::new("group", "artifact", "1.1.0");
+    assert_eq!(Compression::None, repo.compression());
+
+    repo.set_compression(Compression::Zstd)?;
+    repo.save_pom(&compressed, POM)?;
+
+    let mut reopened = Repository::new(repo.base_path());
+    assert_eq!(Compression::Zstd, reopened.compression());
+
+    reopened.set_compression(Compression::None)?;
+    reopened.save_pom(&uncompressed, POM)?;
+    assert_eq!(POM, reopened.read_pom(&compressed)?);
+    assert_eq!(POM, reopened.read_pom(&uncompressed)?);
+    assert_eq!(
+        Compression::None,
+        Repository::new(repo.base_path()).compression()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use super::{create_temp_repository, serve};
+use crate::deploy::DeployOptions;
+use crate::error::RepositoryOperationError;
+use crate::repository::{Artifact, RemoteRepository};
+use std::sync::{Arc, Mutex};
+
+/// Starts a remote repository answering every `HEAD` request with `head_status`, every
+/// `GET` request with `404 Not Found`, and every other request with `200 OK` (*see [`serve`]*).
+fn serve_deployments(head_status: &'static str) -> (RemoteRepository, Arc<Mutex<Vec<String>>>) {
+    serve(move |method, _| match method {
+        "HEAD" => (head_status, String::new()),
+        "GET" => ("404 Not Found", String::new()),
+        _ => ("200 OK", String::new()),
+    })
+}
+
+#[test]
+fn deploy_dry_run() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+    repo.save_artifact(&artifact, "jar")?;
+    repo.save_pom(&artifact, "<project></project>")?;
+    let (remote_repository, requests) = serve_deployments("404 Not Found");
+
+    let report = repo.deploy_to_remote(
+        &artifact,
+        &remote_repository,
+        &DeployOptions {
+            dry_run: true,
+            ..Default::default()
+        },
+    )?;
+
+    let remote_files: Vec<String> = report
+        .files
+        .iter()
+        .map(|file| file.remote_url.path().to_string())
+        .collect();
+    assert_eq!(
+        vec![
+            "/maven2/group/artifact/1.0.0/artifact-1.0.0.jar",
+            "/maven2/group/artifact/1.0.0/artifact-1.0.0.jar.sha1",
+            "/maven2/group/artifact/1.0.0/artifact-1.0.0.pom",
+            "/maven2/group/artifact/1.0.0/artifact-1.0.0.pom.sha1",
+            "/maven2/group/artifact/maven-metadata.xml",
+            "/maven2/group/artifact/maven-metadata.xml.sha1",
+        ],
+        remote_files
+    );
+    assert_eq!(
+        repo.artifact_jar_path(&artifact),
+        report.files[0].local_path
+    );
+    assert!(!requests
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|request| request.starts_with("PUT")));
+
+    Ok(())
+}
+
+#[test]
+fn deploy_uploads_files() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+    repo.save_pom(&artifact, "<project></project>")?;
+    let (remote_repository, requests) = serve_deployments("404 Not Found");
+
+    repo.deploy_to_remote(&artifact, &remote_repository, &DeployOptions::default())?;
+
+    assert_eq!(
+        vec![
+            "HEAD /maven2/group/artifact/1.0.0/artifact-1.0.0.pom HTTP/1.1",
+            "GET /maven2/group/artifact/maven-metadata.xml HTTP/1.1",
+            "PUT /maven2/group/artifact/1.0.0/artifact-1.0.0.pom HTTP/1.1",
+            "PUT /maven2/group/artifact/1.0.0/artifact-1.0.0.pom.sha1 HTTP/1.1",
+            "PUT /maven2/group/artifact/maven-metadata.xml HTTP/1.1",
+            "PUT /maven2/group/artifact/maven-metadata.xml.sha1 HTTP/1.1",
+        ],
+        *requests.lock().unwrap()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deploy_existing_version() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let release = Artifact::new("group", "artifact", "1.0.0");
+    let snapshot = Artifact::new("group", "artifact", "1.1.0-SNAPSHOT");
+    repo.save_pom(&release, "<project></project>")?;
+    repo.save_pom(&snapshot, "<project></project>")?;
+    let (remote_repository, _) = serve_deployments("200 OK");
+    let options = DeployOptions {
+        dry_run: true,
+        ..Default::default()
+    };
+
+    assert!(matches!(
+        repo.deploy_to_remote(&release, &remote_repository, &options),
+        Err(RepositoryOperationError::VersionAlreadyDeployed(_))
+    ));
+    assert!(repo
+        .deploy_to_remote(&snapshot, &remote_repository, &options)
+        .is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn deploy_rejected_check() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+    repo.save_pom(&artifact, "<project></project>")?;
+    let options = DeployOptions {
+        dry_run: true,
+        ..Default::default()
+    };
+
+    for status in [
+        "401 Unauthorized",
+        "403 Forbidden",
+        "500 Internal Server Error",
+    ] {
+        let (remote_repository, _) = serve_deployments(status);
+        assert!(matches!(
+            repo.deploy_to_remote(&artifact, &remote_repository, &options),
+            Err(RepositoryOperationError::GetError(_))
+        ));
+    }
+
+    Ok(())
+}
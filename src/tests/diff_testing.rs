@@ -0,0 +1,104 @@
+use super::create_temp_repository;
+use crate::diff::{ChangedEntry, JarEntry, ManifestChange};
+use crate::repository::Artifact;
+use std::io::Write;
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+fn build_jar(entries: &[(&str, &str)]) -> zip::result::ZipResult<Vec<u8>> {
+    let mut writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = FileOptions::default().compression_method(CompressionMethod::Stored);
+    for (name, contents) in entries {
+        writer.start_file(*name, options)?;
+        writer.write_all(contents.as_bytes())?;
+    }
+    Ok(writer.finish()?.into_inner())
+}
+
+#[test]
+fn diff_artifacts_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let old = Artifact::new("group", "artifact", "1.0.0");
+    let new = Artifact::new("group", "artifact", "1.1.0");
+
+    repo.save_artifact(
+        &old,
+        build_jar(&[
+            (
+                "META-INF/MANIFEST.MF",
+                "Manifest-Version: 1.0\nImplementation-Version: 1.0.0\nCreated-By: \n Maven\n\nName: other\nIgnored: true\n",
+            ),
+            ("a/Same.class", "same"),
+            ("a/Changed.class", "old"),
+            ("a/Removed.class", "removed"),
+        ])?,
+    )?;
+    repo.save_artifact(
+        &new,
+        build_jar(&[
+            (
+                "META-INF/MANIFEST.MF",
+                "Manifest-Version: 1.0\nImplementation-Version: 1.1.0\nAutomatic-Module-Name: artifact\nCreated-By: Maven\n",
+            ),
+            ("a/Same.class", "same"),
+            ("a/Changed.class", "newer"),
+            ("a/Added.class", "added"),
+        ])?,
+    )?;
+
+    let diff = repo.diff_artifacts(&old, &new)?;
+
+    assert_eq!(
+        vec![JarEntry {
+            name: "a/Added.class".to_string(),
+            size: 5
+        }],
+        diff.added
+    );
+    assert_eq!(
+        vec![JarEntry {
+            name: "a/Removed.class".to_string(),
+            size: 7
+        }],
+        diff.removed
+    );
+    assert_eq!(
+        Some(&ChangedEntry {
+            name: "a/Changed.class".to_string(),
+            old_size: 3,
+            new_size: 5
+        }),
+        diff.changed
+            .iter()
+            .find(|entry| entry.name == "a/Changed.class")
+    );
+    assert_eq!(
+        vec![
+            ManifestChange {
+                attribute: "Automatic-Module-Name".to_string(),
+                old_value: None,
+                new_value: Some("artifact".to_string())
+            },
+            ManifestChange {
+                attribute: "Implementation-Version".to_string(),
+                old_value: Some("1.0.0".to_string()),
+                new_value: Some("1.1.0".to_string())
+            },
+        ],
+        diff.manifest_changes
+    );
+    assert!(repo.diff_artifacts(&old, &old)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn diff_mismatched_artifacts() {
+    let (_repo_dir, repo) = create_temp_repository().unwrap();
+
+    assert!(repo
+        .diff_artifacts(
+            &Artifact::new("group", "artifact", "1.0.0"),
+            &Artifact::new("group", "other", "1.0.0"),
+        )
+        .is_err());
+}
@@ -0,0 +1,125 @@
+use super::create_temp_repository;
+use crate::files::{artifact_files, ArtifactFile, FileKind, FileRole, FileSetOptions};
+use crate::repository::{Artifact, RemoteRepository};
+use std::collections::HashSet;
+use url::Url;
+
+fn junit() -> Artifact {
+    Artifact::new("org.junit.jupiter", "junit-jupiter-api", "5.10.2")
+}
+
+#[test]
+fn artifact_files_default_test() {
+    let files = artifact_files(&junit(), &FileSetOptions::default());
+
+    assert_eq!(
+        files
+            .iter()
+            .map(|file| (file.kind, file.role))
+            .collect::<Vec<_>>(),
+        vec![
+            (FileKind::Jar, FileRole::Content),
+            (FileKind::Jar, FileRole::Checksum),
+            (FileKind::Jar, FileRole::Signature),
+            (FileKind::Pom, FileRole::Content),
+            (FileKind::Pom, FileRole::Checksum),
+            (FileKind::Pom, FileRole::Signature),
+        ]
+    );
+}
+
+#[test]
+fn artifact_files_options_test() {
+    let options = FileSetOptions {
+        sources: true,
+        javadoc: false,
+        module: true,
+    };
+    let files = artifact_files(&junit(), &options);
+
+    assert_eq!(files.len(), 12);
+    assert!(!files.iter().any(|file| file.kind == FileKind::Javadoc));
+    assert_eq!(files[3].kind, FileKind::Sources);
+    assert_eq!(files.last().unwrap().kind, FileKind::Pom);
+}
+
+#[test]
+fn artifact_file_local_path_test() -> std::io::Result<()> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let file = ArtifactFile::new(&junit(), FileKind::Sources, FileRole::Checksum);
+
+    assert_eq!(
+        file.local_path(&repo),
+        repo.base_path()
+            .join("org.junit.jupiter")
+            .join("junit-jupiter-api")
+            .join("5.10.2-sources.jar.sha1")
+    );
+    assert_eq!(
+        file.content().local_path(&repo),
+        repo.artifact_jar_path(&junit())
+            .with_file_name("5.10.2-sources.jar")
+    );
+    assert_eq!(
+        ArtifactFile::new(&junit(), FileKind::Pom, FileRole::Content).local_path(&repo),
+        repo.artifact_pom_path(&junit())
+    );
+    Ok(())
+}
+
+#[test]
+fn artifact_file_remote_url_test() {
+    let remote = RemoteRepository {
+        remote_url: Url::parse("https://repo1.maven.org/").unwrap(),
+    };
+    let file = ArtifactFile::new(&junit(), FileKind::Sources, FileRole::Signature);
+
+    assert_eq!(
+        file.remote_url(&remote).unwrap().as_str(),
+        "https://repo1.maven.org/maven2/org/junit/jupiter/junit-jupiter-api/5.10.2/junit-jupiter-api-5.10.2-sources.jar.asc"
+    );
+    assert_eq!(
+        ArtifactFile::new(&junit(), FileKind::Jar, FileRole::Content)
+            .remote_url(&remote)
+            .unwrap(),
+        remote.jar_artifact_url(&junit()).unwrap()
+    );
+}
+
+#[test]
+fn dotless_version_paths_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (_shared_dir, shared) = create_temp_repository()?;
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let remote = RemoteRepository {
+        remote_url: Url::parse("https://repo1.maven.org/").unwrap(),
+    };
+    let dotless = Artifact::new("xmlpull", "xmlpull", "20090211");
+
+    assert_eq!(
+        repo.base_path()
+            .join("xmlpull")
+            .join("xmlpull")
+            .join("20090211.jar"),
+        repo.artifact_jar_path(&dotless)
+    );
+    assert_eq!(
+        repo.artifact_jar_path(&dotless)
+            .with_file_name("20090211.module"),
+        repo.artifact_module_path(&dotless)
+    );
+    assert_eq!(
+        "https://repo1.maven.org/maven2/xmlpull/xmlpull/20090211/xmlpull-20090211.pom",
+        remote.pom_artifact_url(&dotless)?.as_str()
+    );
+
+    // The files saved are the ones expected by the rest of the repository.
+    shared.save_pom(&dotless, "<project></project>")?;
+    shared.save_artifact(&dotless, "jar")?;
+    assert_eq!(4, repo.adopt_from(&shared, &dotless)?.len());
+    assert_eq!(
+        Some(HashSet::from(["20090211".to_string()])),
+        repo.get_artifact_available_versions(&dotless)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use super::{create_temp_repository, serve, serve_files};
+use crate::mirror::{MirrorJob, MirrorTarget};
+use crate::operation::OperationContext;
+use crate::repository::{Artifact, RemoteRepository};
+use std::collections::HashMap;
+
+const METADATA: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<metadata>
+  <groupId>group</groupId>
+  <artifactId>artifact</artifactId>
+  <versioning>
+    <latest>1.2.0</latest>
+    <release>1.2.0</release>
+    <versions>
+      <version>0.9.0</version>
+      <version>1.0.0</version>
+      <version>1.1.0</version>
+      <version>1.2.0</version>
+    </versions>
+  </versioning>
+</metadata>"#;
+
+#[test]
+fn mirror_group() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    repo.save_pom(&Artifact::new("group", "artifact", "1.0.0"), "<project/>")?;
+    repo.save_pom(&Artifact::new("other", "artifact", "1.0.0"), "<project/>")?;
+
+    let (remote, _) = serve_files(HashMap::from([
+        ("/maven2/group/artifact/maven-metadata.xml", METADATA),
+        (
+            "/maven2/group/artifact/1.1.0/artifact-1.1.0.pom",
+            "<project/>",
+        ),
+        ("/maven2/group/artifact/1.1.0/artifact-1.1.0.jar", "jar"),
+    ]));
+    let job = MirrorJob {
+        targets: vec![MirrorTarget::Group("group".to_string())],
+        remotes: vec![remote],
+        metadata_only: false,
+    };
+    let context = OperationContext::new();
+
+    let report = job.run(&repo, &context)?;
+
+    assert_eq!(1, report.artifacts.len());
+    let sync = &report.artifacts[0];
+    assert_eq!(
+        ("group", "artifact"),
+        (sync.group_id.as_str(), sync.artifact_id.as_str())
+    );
+    assert!(sync.metadata_updated);
+    assert_eq!(vec!["1.1.0".to_string()], sync.new_versions);
+    assert_eq!(1, sync.failures.len());
+    assert_eq!(Some("1.2.0".to_string()), sync.failures[0].version);
+    assert!(!report.is_success());
+
+    let saved = Artifact::new("group", "artifact", "1.1.0");
+    assert!(repo.exists(&saved));
+    assert!(repo.artifact_jar_path(&saved).exists());
+    assert_eq!(
+        METADATA.as_bytes(),
+        std::fs::read(repo.artifact_metadata_path(&saved))?
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json())?;
+    assert_eq!("1.1.0", json["artifacts"][0]["new_versions"][0]);
+    assert_eq!(context.id.0, json["operation"]);
+    let failure = &json["artifacts"][0]["failures"][0];
+    assert_eq!("1.2.0", failure["version"]);
+    assert_eq!("get_error", failure["kind"]);
+    assert!(failure["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("request to the remote repository failed"));
+
+    // Nothing changes the second time.
+    let report = job.run(&repo, &OperationContext::new())?;
+    assert!(!report.artifacts[0].metadata_updated);
+    assert!(report.artifacts[0].new_versions.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn mirror_explicit_artifact_metadata_only() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let (remote, _) = serve_files(HashMap::from([(
+        "/maven2/group/artifact/1.0.0/artifact-1.0.0.pom",
+        "<project/>",
+    )]));
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+    let job = MirrorJob {
+        targets: vec![MirrorTarget::Artifact(artifact.clone())],
+        remotes: vec![remote],
+        metadata_only: true,
+    };
+
+    let report = job.run(&repo, &OperationContext::new())?;
+
+    assert!(report.is_success());
+    assert_eq!(vec!["1.0.0".to_string()], report.artifacts[0].new_versions);
+    assert!(!report.artifacts[0].metadata_updated);
+    assert!(repo.exists(&artifact));
+    assert!(!repo.artifact_jar_path(&artifact).exists());
+
+    Ok(())
+}
+
+#[test]
+fn mirror_cancelled() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let context = OperationContext::new();
+    context.cancellation.cancel();
+    let job = MirrorJob {
+        targets: vec![MirrorTarget::Artifact(Artifact::new(
+            "group", "artifact", "1.0.0",
+        ))],
+        remotes: vec![RemoteRepository::default()],
+        metadata_only: false,
+    };
+
+    let error = job.run(&repo, &context).unwrap_err();
+
+    assert_eq!(Some(context.id), error.operation());
+    assert_eq!(1, error.progress().unwrap().pending.len());
+
+    Ok(())
+}
+
+#[test]
+fn mirror_failing_remote() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    repo.save_pom(&Artifact::new("group", "artifact", "1.0.0"), "<project/>")?;
+
+    let (failing_remote, _) = serve(|_, _| ("500 Internal Server Error", String::new()));
+    let (remote, _) = serve_files(HashMap::from([
+        ("/maven2/group/artifact/maven-metadata.xml", METADATA),
+        (
+            "/maven2/group/artifact/1.1.0/artifact-1.1.0.pom",
+            "<project/>",
+        ),
+        (
+            "/maven2/group/artifact/1.2.0/artifact-1.2.0.pom",
+            "<project/>",
+        ),
+    ]));
+    let job = MirrorJob {
+        targets: vec![MirrorTarget::Group("group".to_string())],
+        remotes: vec![
+            RemoteRepository {
+                remote_url: failing_remote.remote_url.clone(),
+            },
+            remote,
+        ],
+        metadata_only: true,
+    };
+
+    let report = job.run(&repo, &OperationContext::new())?;
+
+    assert!(report.is_success());
+    assert_eq!(
+        vec!["1.1.0".to_string(), "1.2.0".to_string()],
+        report.artifacts[0].new_versions
+    );
+    assert_eq!(
+        METADATA.as_bytes(),
+        std::fs::read(repo.artifact_metadata_path(&Artifact::new("group", "artifact", "")))?
+    );
+
+    // Failing remote repositories are reported if no other one has the metadata.
+    let job = MirrorJob {
+        remotes: vec![failing_remote],
+        ..job
+    };
+    let report = job.run(&repo, &OperationContext::new())?;
+    assert!(!report.is_success());
+
+    Ok(())
+}
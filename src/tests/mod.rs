@@ -3,3 +3,118 @@ mod utils_testing;
 
 #[cfg(test)]
 mod repository_testing;
+
+#[cfg(test)]
+mod report_testing;
+
+#[cfg(test)]
+mod validation_testing;
+
+#[cfg(test)]
+mod diff_testing;
+
+#[cfg(test)]
+mod deploy_testing;
+
+#[cfg(test)]
+mod compression_testing;
+
+#[cfg(test)]
+mod operation_testing;
+
+#[cfg(test)]
+mod mirror_testing;
+
+#[cfg(test)]
+mod files_testing;
+
+use crate::repository::{RemoteRepository, Repository};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use tempdir::TempDir;
+use url::Url;
+
+/// Creates a repository located in a new temporary directory, along with the
+/// directory itself, which gets removed when dropped. Keep it alive (*i.e. bound
+/// to `_repo_dir`*) for as long as the repository is used.
+fn create_temp_repository() -> std::io::Result<(TempDir, Repository)> {
+    let tmp_dir = TempDir::new("jaburepository")?;
+    let repository = Repository::new(tmp_dir.path());
+    Ok((tmp_dir, repository))
+}
+
+/// Starts a remote repository on a local port, answering every request with the
+/// status and body returned by `respond`, which gets passed the method and the path
+/// of the request. Returns the remote repository, and the request lines received by
+/// it (*i.e. `GET /maven2/group/artifact/maven-metadata.xml HTTP/1.1`*).
+fn serve<F>(respond: F) -> (RemoteRepository, Arc<Mutex<Vec<String>>>)
+where
+    F: Fn(&str, &str) -> (&'static str, String) + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let remote_url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let received = requests.clone();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            reader
+                .by_ref()
+                .take(content_length)
+                .read_to_end(&mut Vec::new())
+                .unwrap();
+
+            let mut request = request_line.split_whitespace();
+            let method = request.next().unwrap_or_default();
+            let path = request.next().unwrap_or_default();
+            let (status, body) = respond(method, path);
+            received
+                .lock()
+                .unwrap()
+                .push(request_line.trim().to_string());
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            )
+            .unwrap();
+            if method != "HEAD" {
+                stream.write_all(body.as_bytes()).unwrap();
+            }
+        }
+    });
+
+    (RemoteRepository { remote_url }, requests)
+}
+
+/// Starts a remote repository serving the given files (*by path*), answering
+/// `404 Not Found` to everything else (*see [`serve`]*).
+fn serve_files(
+    files: HashMap<&'static str, &'static str>,
+) -> (RemoteRepository, Arc<Mutex<Vec<String>>>) {
+    serve(move |_, path| match files.get(path) {
+        Some(body) => ("200 OK", body.to_string()),
+        None => ("404 Not Found", String::new()),
+    })
+}
@@ -0,0 +1,94 @@
+use super::create_temp_repository;
+use crate::error::RepositoryOperationError;
+use crate::operation::{OperationContext, Progress};
+use crate::repository::{Artifact, RemoteRepository};
+use std::time::Duration;
+
+fn sample_artifact() -> Artifact {
+    Artifact::new("org.junit.jupiter", "junit-jupiter-api", "5.10.2")
+}
+
+#[test]
+fn cancelled_operation() {
+    let (_repo_dir, repo) = create_temp_repository().unwrap();
+    let context = OperationContext::new();
+    context.cancellation.clone().cancel();
+
+    let result = repo.recursive_save_from_remote_with_context(
+        &sample_artifact(),
+        &RemoteRepository::default(),
+        &context,
+        |_, _| panic!("Nothing should be downloaded"),
+    );
+
+    match result {
+        Err(RepositoryOperationError::Cancelled {
+            operation,
+            progress,
+        }) => {
+            assert_eq!(context.id, operation);
+            assert_eq!(
+                Progress {
+                    completed: Vec::new(),
+                    pending: vec![sample_artifact()],
+                },
+                progress
+            );
+        }
+        _ => panic!("The operation should have been cancelled"),
+    }
+}
+
+#[test]
+fn deadline_exceeded() {
+    let (_repo_dir, repo) = create_temp_repository().unwrap();
+    let context = OperationContext::with_timeout(Duration::ZERO);
+
+    let error = repo
+        .recursive_save_from_remote_with_context(
+            &sample_artifact(),
+            &RemoteRepository::default(),
+            &context,
+            |_, _| panic!("Nothing should be downloaded"),
+        )
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        RepositoryOperationError::DeadlineExceeded { .. }
+    ));
+    assert_eq!(Some(context.id), error.operation());
+    assert_eq!(vec![sample_artifact()], error.progress().unwrap().pending);
+}
+
+#[test]
+fn network_timeout() {
+    // A listener that never answers.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let request_error = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_millis(50))
+        .build()
+        .unwrap()
+        .get(&url)
+        .send()
+        .unwrap_err();
+    let context = OperationContext::new();
+
+    match context.interrupted(request_error.into(), Progress::default) {
+        RepositoryOperationError::NetworkTimeout {
+            operation,
+            url: timed_out_url,
+            ..
+        } => {
+            assert_eq!(context.id, operation);
+            assert_eq!(Some(url), timed_out_url);
+        }
+        error => panic!("Expected a network timeout, found {error}"),
+    }
+}
+
+#[test]
+fn unique_operation_ids() {
+    assert_ne!(OperationContext::new().id, OperationContext::new().id);
+}
@@ -0,0 +1,37 @@
+use crate::report::{ArtifactReport, ResolutionReport, Timings};
+use crate::repository::Artifact;
+use std::time::Duration;
+
+fn sample_timings(millis: u64) -> Timings {
+    Timings {
+        metadata_fetch: Duration::from_millis(millis),
+        parse: Duration::from_millis(millis * 2),
+        download: Duration::from_millis(millis * 3),
+        disk_write: Duration::from_millis(millis * 4),
+        verification: Duration::from_millis(millis * 5),
+    }
+}
+
+#[test]
+fn timings_total() {
+    assert_eq!(Duration::from_millis(15), sample_timings(1).total());
+}
+
+#[test]
+fn report_aggregate() {
+    let report = ResolutionReport {
+        artifacts: vec![
+            ArtifactReport {
+                artifact: Artifact::new("group", "first", "1.0.0"),
+                timings: sample_timings(1),
+            },
+            ArtifactReport {
+                artifact: Artifact::new("group", "second", "1.0.0"),
+                timings: sample_timings(2),
+            },
+        ],
+    };
+
+    assert_eq!(sample_timings(3), report.aggregate());
+    assert_eq!(3, report.to_string().lines().count());
+}
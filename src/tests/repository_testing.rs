@@ -1,4 +1,8 @@
-use crate::repository::{Artifact, RemoteRepository, Repository};
+use super::{create_temp_repository, serve_files};
+use crate::error::RepositoryOperationError;
+use crate::files::{ArtifactFile, FileKind, FileRole};
+use crate::repository::{Artifact, RemoteRepository};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use url::Url;
 
@@ -6,16 +10,9 @@ fn sample_artifact() -> Artifact {
     Artifact::new("org.junit.jupiter", "junit-jupiter-api", "5.10.2")
 }
 
-fn create_temp_repository() -> std::io::Result<Repository> {
-    let tmp_dir = tempdir::TempDir::new("jaburepository")?
-        .path()
-        .to_path_buf();
-    Ok(Repository::new(tmp_dir))
-}
-
 #[test]
 fn download_artifact() -> Result<(), Box<dyn std::error::Error>> {
-    let repo = create_temp_repository()?;
+    let (_repo_dir, repo) = create_temp_repository()?;
 
     // Download artifacts from the maven repository.
     let remote_repo = RemoteRepository::default();
@@ -35,7 +32,7 @@ fn download_artifact() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn jar_artifact_path_forming() {
-    let repo = create_temp_repository().unwrap();
+    let (_repo_dir, repo) = create_temp_repository().unwrap();
     let artifact = Artifact {
         group_id: "group".to_string(),
         artifact_id: "artifact".to_string(),
@@ -68,7 +65,7 @@ fn base_artifact_url() {
 
 #[test]
 fn save_from_remote_test() {
-    let repo = create_temp_repository().unwrap();
+    let (_repo_dir, repo) = create_temp_repository().unwrap();
     let remote_repository = RemoteRepository::default();
     let target_artifact = sample_artifact();
 
@@ -78,7 +75,7 @@ fn save_from_remote_test() {
 
 #[test]
 fn recursive_save_from_remote_test() {
-    let repo = create_temp_repository().unwrap();
+    let (_repo_dir, repo) = create_temp_repository().unwrap();
     let remote_repository = RemoteRepository::default();
     let target_artifact = sample_artifact();
 
@@ -87,3 +84,403 @@ fn recursive_save_from_remote_test() {
     })
     .unwrap();
 }
+
+#[test]
+fn recursive_save_follows_transitive_dependencies() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let (remote_repository, _) = serve_files(HashMap::from([
+        (
+            "/maven2/group/app/1.0.0/app-1.0.0.pom",
+            "<project><dependencies>\
+                <dependency><groupId>group</groupId><artifactId>lib</artifactId><version>2.0</version></dependency>\
+            </dependencies></project>",
+        ),
+        ("/maven2/group/app/1.0.0/app-1.0.0.jar", "app jar"),
+        (
+            "/maven2/group/lib/2.0/lib-2.0.pom",
+            "<project><dependencies>\
+                <dependency><groupId>group</groupId><artifactId>deep</artifactId><version>3.0</version></dependency>\
+            </dependencies></project>",
+        ),
+        ("/maven2/group/lib/2.0/lib-2.0.jar", "lib jar"),
+        ("/maven2/group/deep/3.0/deep-3.0.pom", "<project/>"),
+        ("/maven2/group/deep/3.0/deep-3.0.jar", "deep jar"),
+    ]));
+
+    let report = repo.recursive_save_from_remote(
+        &Artifact::new("group", "app", "1.0.0"),
+        &remote_repository,
+        |_, _| {},
+    )?;
+
+    assert_eq!(
+        vec![
+            Artifact::new("group", "app", "1.0.0"),
+            Artifact::new("group", "lib", "2.0"),
+            Artifact::new("group", "deep", "3.0"),
+        ],
+        report
+            .artifacts
+            .iter()
+            .map(|saved| saved.artifact.clone())
+            .collect::<Vec<_>>()
+    );
+    assert!(repo
+        .artifact_jar_path(&Artifact::new("group", "deep", "3.0"))
+        .exists());
+
+    Ok(())
+}
+
+#[test]
+fn install_dir_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let drop_dir = tempdir::TempDir::new("jaburepo-drop")?;
+
+    std::fs::write(
+        drop_dir.path().join("artifact-1.0.0.pom"),
+        "<project><groupId>group</groupId><artifactId>artifact</artifactId><version>1.0.0</version></project>",
+    )?;
+    std::fs::write(drop_dir.path().join("artifact-1.0.0.jar"), "jar")?;
+    std::fs::write(drop_dir.path().join("standalone-2.1.jar"), "standalone jar")?;
+
+    let mut installed = repo.install_dir(drop_dir.path(), |_, guess| {
+        assert_eq!(Artifact::new("", "standalone", "2.1"), guess);
+        Some(Artifact {
+            group_id: "group".to_string(),
+            ..guess
+        })
+    })?;
+    installed.sort_by(|a, b| a.artifact_id.cmp(&b.artifact_id));
+
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+    let standalone = Artifact::new("group", "standalone", "2.1");
+    assert_eq!(vec![artifact.clone(), standalone.clone()], installed);
+    assert!(repo.exists(&artifact));
+    assert_eq!(
+        "jar",
+        std::fs::read_to_string(repo.artifact_jar_path(&artifact))?
+    );
+    assert!(repo.artifact_jar_path(&standalone).exists());
+
+    Ok(())
+}
+
+#[test]
+fn install_dir_classified_jars() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let drop_dir = tempdir::TempDir::new("jaburepo-drop")?;
+
+    std::fs::write(
+        drop_dir.path().join("artifact-1.0.pom"),
+        "<project><groupId>group</groupId><artifactId>artifact</artifactId><version>1.0</version></project>",
+    )?;
+    std::fs::write(drop_dir.path().join("artifact-1.0.jar"), "jar")?;
+    std::fs::write(drop_dir.path().join("artifact-1.0-sources.jar"), "sources")?;
+    std::fs::write(drop_dir.path().join("artifact-1.0-javadoc.jar"), "javadoc")?;
+
+    let installed = repo.install_dir(drop_dir.path(), |_, _| None)?;
+
+    let artifact = Artifact::new("group", "artifact", "1.0");
+    assert_eq!(vec![artifact.clone()], installed);
+    for (kind, content) in [
+        (FileKind::Jar, "jar"),
+        (FileKind::Sources, "sources"),
+        (FileKind::Javadoc, "javadoc"),
+    ] {
+        let path = ArtifactFile::new(&artifact, kind, FileRole::Content).local_path(&repo);
+        assert_eq!(content, std::fs::read_to_string(&path)?);
+        assert!(crate::utils::checksum_path(path).exists());
+    }
+    assert_eq!(
+        Some(HashSet::from(["1.0".to_string()])),
+        repo.get_artifact_available_versions(&artifact)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn install_dir_unresolved_coordinates() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let drop_dir = tempdir::TempDir::new("jaburepo-drop")?;
+
+    std::fs::write(
+        drop_dir.path().join("artifact-1.0.0.pom"),
+        "<project><groupId>group</groupId><artifactId>artifact</artifactId><version>1.0.0</version></project>",
+    )?;
+    std::fs::write(drop_dir.path().join("standalone-2.1.jar"), "standalone jar")?;
+
+    assert!(repo.install_dir(drop_dir.path(), |_, _| None).is_err());
+    assert!(!repo.exists(&Artifact::new("group", "artifact", "1.0.0")));
+
+    Ok(())
+}
+
+#[test]
+fn install_dir_removes_created_dirs() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let drop_dir = tempdir::TempDir::new("jaburepo-drop")?;
+
+    std::fs::write(
+        drop_dir.path().join("artifact-1.0.0.pom"),
+        "<project><groupId>group</groupId><artifactId>artifact</artifactId><version>1.0.0</version></project>",
+    )?;
+    std::fs::write(
+        drop_dir.path().join("blocked-1.0.0.pom"),
+        "<project><groupId>blocked</groupId><artifactId>blocked</artifactId><version>1.0.0</version></project>",
+    )?;
+    // The directory of the group can't be created, since there's a file in its place.
+    std::fs::create_dir_all(repo.base_path())?;
+    std::fs::write(repo.base_path().join("blocked"), "")?;
+
+    assert!(repo.install_dir(drop_dir.path(), |_, _| None).is_err());
+    assert!(!repo.base_path().join("group").exists());
+
+    Ok(())
+}
+
+#[test]
+fn install_dir_restores_replaced_files() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let drop_dir = tempdir::TempDir::new("jaburepo-drop")?;
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+    repo.save_pom(&artifact, "old pom")?;
+    repo.save_artifact(&artifact, "old jar")?;
+
+    std::fs::write(
+        drop_dir.path().join("artifact-1.0.0.pom"),
+        "<project><groupId>group</groupId><artifactId>artifact</artifactId><version>1.0.0</version></project>",
+    )?;
+    std::fs::write(drop_dir.path().join("artifact-1.0.0.jar"), "new jar")?;
+    // The old jar can't be put aside, so the installation fails after the pom got moved.
+    let mut replaced_jar = repo.artifact_jar_path(&artifact).into_os_string();
+    replaced_jar.push(".replaced");
+    std::fs::create_dir_all(PathBuf::from(replaced_jar).join("blocker"))?;
+
+    assert!(repo.install_dir(drop_dir.path(), |_, _| None).is_err());
+    assert_eq!("old pom", repo.read_pom(&artifact)?);
+    assert_eq!(
+        "old jar",
+        std::fs::read_to_string(repo.artifact_jar_path(&artifact))?
+    );
+    for entry in std::fs::read_dir(repo.artifact_jar_path(&artifact).parent().unwrap())? {
+        let file_name = entry?.file_name().to_string_lossy().to_string();
+        assert!(!file_name.ends_with(".install"), "{file_name} left behind");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn available_versions_ignore_checksums() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+
+    repo.save_pom(&artifact, "<project></project>")?;
+    assert!(crate::utils::checksum_path(repo.artifact_pom_path(&artifact)).exists());
+
+    let versions = repo.get_artifact_available_versions(&artifact).unwrap();
+    assert_eq!(1, versions.len());
+    assert!(versions.contains("1.0.0"));
+
+    Ok(())
+}
+
+#[test]
+fn metadata_artifact_url() {
+    let remote_repository = RemoteRepository::default();
+    let expected =
+        "https://repo1.maven.org/maven2/org/junit/jupiter/junit-jupiter-api/maven-metadata.xml";
+
+    assert_eq!(
+        expected,
+        remote_repository
+            .metadata_artifact_url(&sample_artifact())
+            .unwrap()
+            .as_str()
+    );
+    assert_eq!(
+        "https://repo1.maven.org/maven2/org/junit/jupiter/junit-jupiter-api/5.10.2/junit-jupiter-api-5.10.2.module",
+        remote_repository
+            .module_artifact_url(&sample_artifact())
+            .unwrap()
+            .as_str()
+    );
+}
+
+#[test]
+fn mirror_metadata_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let (remote_repository, requests) = serve_files(HashMap::from([
+        (
+            "/maven2/group/app/1.0.0/app-1.0.0.pom",
+            "<project><dependencies>\
+                <dependency><groupId>group.sub</groupId><artifactId>lib</artifactId><version>2.0</version></dependency>\
+                <dependency><groupId>other</groupId><artifactId>outside</artifactId><version>1.0</version></dependency>\
+            </dependencies></project>",
+        ),
+        ("/maven2/group/app/1.0.0/app-1.0.0.module", "{}"),
+        ("/maven2/group/app/1.0.0/app-1.0.0.jar", "jar"),
+        ("/maven2/group/app/maven-metadata.xml", "<metadata/>"),
+        ("/maven2/group/sub/lib/2.0/lib-2.0.pom", "<project/>"),
+        ("/maven2/other/outside/1.0/outside-1.0.pom", "<project/>"),
+    ]));
+    let app = Artifact::new("group", "app", "1.0.0");
+    let lib = Artifact::new("group.sub", "lib", "2.0");
+    let outside = Artifact::new("other", "outside", "1.0");
+
+    let report = repo.mirror_metadata(
+        std::slice::from_ref(&app),
+        &["group".to_string()],
+        &remote_repository,
+        |_| {},
+    )?;
+
+    assert_eq!(2, report.artifacts.len());
+    assert!(repo.exists(&app));
+    assert!(repo.artifact_module_path(&app).exists());
+    assert!(repo.artifact_metadata_path(&app).exists());
+    assert!(!repo.artifact_jar_path(&app).exists());
+    // Dependencies are followed only inside the groups mirrored.
+    assert!(repo.exists(&lib));
+    assert!(!repo.exists(&outside));
+    assert!(!requests
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|request| request.contains(".jar") || request.contains("/other/")));
+
+    Ok(())
+}
+
+#[test]
+fn recursive_save_after_mirror_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let (remote_repository, _) = serve_files(HashMap::from([
+        (
+            "/maven2/group/app/1.0.0/app-1.0.0.pom",
+            "<project><dependencies>\
+                <dependency><groupId>group</groupId><artifactId>lib</artifactId><version>2.0</version></dependency>\
+            </dependencies></project>",
+        ),
+        ("/maven2/group/app/1.0.0/app-1.0.0.jar", "app jar"),
+        ("/maven2/group/lib/2.0/lib-2.0.pom", "<project/>"),
+        ("/maven2/group/lib/2.0/lib-2.0.jar", "lib jar"),
+    ]));
+    let app = Artifact::new("group", "app", "1.0.0");
+    let lib = Artifact::new("group", "lib", "2.0");
+
+    repo.mirror_metadata(
+        std::slice::from_ref(&app),
+        &["group".to_string()],
+        &remote_repository,
+        |_| {},
+    )?;
+    assert!(repo.exists(&lib) && !repo.has_jar(&lib));
+
+    let report = repo.recursive_save_from_remote(&app, &remote_repository, |_, _| {})?;
+
+    assert_eq!(2, report.artifacts.len());
+    assert!(repo.has_jar(&app));
+    assert_eq!(
+        "lib jar",
+        std::fs::read_to_string(repo.artifact_jar_path(&lib))?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn adopt_from_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (_shared_dir, shared) = create_temp_repository()?;
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+
+    shared.save_pom(&artifact, "<project></project>")?;
+    shared.save_artifact(&artifact, "jar")?;
+
+    let adopted = repo.adopt_from(&shared, &artifact)?;
+
+    assert_eq!(4, adopted.len());
+    assert!(repo.exists(&artifact));
+    assert_eq!(
+        "jar",
+        std::fs::read_to_string(repo.artifact_jar_path(&artifact))?
+    );
+    assert!(crate::utils::checksum_path(repo.artifact_jar_path(&artifact)).exists());
+
+    Ok(())
+}
+
+#[test]
+fn save_after_adopt_from() -> Result<(), Box<dyn std::error::Error>> {
+    let (_shared_dir, shared) = create_temp_repository()?;
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+
+    shared.save_pom(&artifact, "<project></project>")?;
+    shared.save_artifact(&artifact, "jar")?;
+    repo.adopt_from(&shared, &artifact)?;
+
+    repo.save_artifact(&artifact, "modified jar")?;
+
+    assert_eq!(
+        "modified jar",
+        std::fs::read_to_string(repo.artifact_jar_path(&artifact))?
+    );
+    assert_eq!(
+        "jar",
+        std::fs::read_to_string(shared.artifact_jar_path(&artifact))?
+    );
+    assert_eq!(
+        crate::utils::sha1_hex("jar"),
+        std::fs::read_to_string(crate::utils::checksum_path(
+            shared.artifact_jar_path(&artifact)
+        ))?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn adopt_from_missing_artifact() -> Result<(), Box<dyn std::error::Error>> {
+    let (_shared_dir, shared) = create_temp_repository()?;
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+
+    assert!(matches!(
+        repo.adopt_from(&shared, &artifact),
+        Err(RepositoryOperationError::ArtifactNotFound(not_found)) if not_found == artifact
+    ));
+    assert!(!repo.exists(&artifact));
+
+    Ok(())
+}
+
+#[test]
+fn adopt_from_corrupted() -> Result<(), Box<dyn std::error::Error>> {
+    let (_shared_dir, shared) = create_temp_repository()?;
+    let (_repo_dir, repo) = create_temp_repository()?;
+    let artifact = Artifact::new("group", "artifact", "1.0.0");
+
+    shared.save_pom(&artifact, "<project></project>")?;
+    shared.save_artifact(&artifact, "jar")?;
+    std::fs::write(shared.artifact_jar_path(&artifact), "corrupted jar")?;
+
+    assert!(matches!(
+        repo.adopt_from(&shared, &artifact),
+        Err(RepositoryOperationError::ChecksumMismatch { .. })
+    ));
+    assert!(!repo.exists(&artifact));
+
+    std::fs::remove_file(crate::utils::checksum_path(
+        shared.artifact_jar_path(&artifact),
+    ))?;
+    assert!(matches!(
+        repo.adopt_from(&shared, &artifact),
+        Err(RepositoryOperationError::MissingChecksum(_))
+    ));
+
+    Ok(())
+}
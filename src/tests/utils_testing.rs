@@ -60,3 +60,127 @@ fn testing_dependencies_from_pom() {
         crate::utils::dependencies_in_pom(SAMPLE_VALID_POM).unwrap()
     )
 }
+
+#[test]
+fn testing_artifact_in_pom() {
+    assert_eq!(
+        Some(Artifact::new("me.folgue", "adt_tar4", "1.0-SNAPSHOT")),
+        crate::utils::artifact_in_pom(SAMPLE_VALID_POM).unwrap()
+    );
+
+    let inherited = r#"
+<project>
+    <parent>
+        <groupId>org.junit</groupId>
+        <artifactId>junit-bom</artifactId>
+        <version>5.10.2</version>
+    </parent>
+    <artifactId>junit-jupiter-api</artifactId>
+</project>
+"#;
+    assert_eq!(
+        Some(Artifact::new("org.junit", "junit-jupiter-api", "5.10.2")),
+        crate::utils::artifact_in_pom(inherited).unwrap()
+    );
+}
+
+#[test]
+fn testing_artifact_from_filename() {
+    assert_eq!(
+        Artifact::new("", "junit-jupiter-api", "5.10.2"),
+        crate::utils::artifact_from_filename("junit-jupiter-api-5.10.2")
+    );
+    assert_eq!(
+        Artifact::new("", "no-version", ""),
+        crate::utils::artifact_from_filename("no-version")
+    );
+}
+
+#[test]
+fn testing_sha1_hex() {
+    assert_eq!(
+        "a9993e364706816aba3e25717850c26c9cd0d89d",
+        crate::utils::sha1_hex("abc")
+    );
+}
+
+#[test]
+fn testing_dependencies_from_pom_without_dependencies() {
+    assert_eq!(
+        Vec::<Artifact>::new(),
+        crate::utils::dependencies_in_pom("<project><artifactId>leaf</artifactId></project>")
+            .unwrap()
+    );
+}
+
+#[test]
+fn testing_group_matches() {
+    assert!(crate::utils::group_matches("org.junit", "org.junit"));
+    assert!(crate::utils::group_matches(
+        "org.junit.jupiter",
+        "org.junit"
+    ));
+    assert!(!crate::utils::group_matches("org.junitx", "org.junit"));
+    assert!(!crate::utils::group_matches("org", "org.junit"));
+}
+
+#[test]
+fn testing_versions_in_metadata() {
+    let metadata = r#"<?xml version="1.0" encoding="UTF-8"?>
+<metadata>
+  <groupId>org.junit.jupiter</groupId>
+  <artifactId>junit-jupiter-api</artifactId>
+  <versioning>
+    <latest>5.10.2</latest>
+    <versions>
+      <version>5.10.1</version>
+      <version>5.10.2</version>
+    </versions>
+    <lastUpdated>20240205160000</lastUpdated>
+  </versioning>
+</metadata>"#;
+
+    assert_eq!(
+        vec!["5.10.1".to_string(), "5.10.2".to_string()],
+        crate::utils::versions_in_metadata(metadata).unwrap()
+    );
+}
+
+#[test]
+fn testing_metadata_with_version() {
+    let artifact = Artifact::new("group", "artifact", "1.1.0");
+    let metadata = crate::utils::metadata_with_version(None, &artifact).unwrap();
+
+    assert_eq!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<metadata>
+  <groupId>group</groupId>
+  <artifactId>artifact</artifactId>
+  <versioning>
+    <latest>1.1.0</latest>
+    <release>1.1.0</release>
+    <versions>
+      <version>1.1.0</version>
+    </versions>
+  </versioning>
+</metadata>
+"#,
+        metadata
+    );
+
+    let snapshot = Artifact::new("group", "artifact", "1.2.0-SNAPSHOT");
+    let metadata = crate::utils::metadata_with_version(Some(&metadata), &snapshot).unwrap();
+    assert_eq!(
+        vec!["1.1.0".to_string(), "1.2.0-SNAPSHOT".to_string()],
+        crate::utils::versions_in_metadata(&metadata).unwrap()
+    );
+    assert!(metadata.contains("<latest>1.2.0-SNAPSHOT</latest>"));
+    assert!(metadata.contains("<release>1.1.0</release>"));
+
+    // Redeployed snapshots aren't listed twice.
+    let metadata = crate::utils::metadata_with_version(Some(&metadata), &snapshot).unwrap();
+    assert_eq!(
+        vec!["1.1.0".to_string(), "1.2.0-SNAPSHOT".to_string()],
+        crate::utils::versions_in_metadata(&metadata).unwrap()
+    );
+}
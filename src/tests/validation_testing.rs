@@ -0,0 +1,88 @@
+use crate::validation::{validate_pom, PomDiagnosticKind};
+
+const VALID_POM: &str = r#"
+<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+    <modelVersion>4.0.0</modelVersion>
+    <parent>
+        <groupId>org.junit</groupId>
+        <artifactId>junit-bom</artifactId>
+        <version>5.10.2</version>
+    </parent>
+    <artifactId>junit-jupiter-api</artifactId>
+    <properties>
+        <anything>goes</anything>
+    </properties>
+    <dependencies>
+        <dependency>
+            <groupId>org.opentest4j</groupId>
+            <artifactId>opentest4j</artifactId>
+            <version>1.3.0</version>
+        </dependency>
+    </dependencies>
+</project>
+"#;
+
+#[test]
+fn valid_pom() {
+    assert_eq!(
+        Vec::<String>::new(),
+        validate_pom(VALID_POM)
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn invalid_pom() {
+    let pom = r#"<project>
+    <modelVersion>3.0.0</modelVersion>
+    <artifactId>artifact</artifactId>
+    <artifactId>artifact</artifactId>
+    <dependencies>
+        <dependency>
+            <artifactId>dependency</artifactId>
+            <color>red</color>
+        </dependency>
+    </dependencies>
+</project>"#;
+
+    let kinds: Vec<PomDiagnosticKind> = validate_pom(pom).into_iter().map(|d| d.kind).collect();
+    assert_eq!(
+        vec![
+            PomDiagnosticKind::WrongNamespace(None),
+            PomDiagnosticKind::DuplicateElement {
+                parent: "project".to_string(),
+                element: "artifactId".to_string()
+            },
+            PomDiagnosticKind::UnexpectedElement {
+                parent: "dependency".to_string(),
+                element: "color".to_string()
+            },
+            PomDiagnosticKind::MissingElement {
+                parent: "dependency".to_string(),
+                element: "groupId".to_string()
+            },
+            PomDiagnosticKind::MissingElement {
+                parent: "project".to_string(),
+                element: "groupId".to_string()
+            },
+            PomDiagnosticKind::MissingElement {
+                parent: "project".to_string(),
+                element: "version".to_string()
+            },
+            PomDiagnosticKind::UnsupportedModelVersion("3.0.0".to_string()),
+        ],
+        kinds
+    );
+}
+
+#[test]
+fn malformed_pom() {
+    let diagnostics = validate_pom("<project><modelVersion>4.0.0</project>");
+    assert!(matches!(
+        diagnostics.last().map(|d| &d.kind),
+        Some(PomDiagnosticKind::MalformedXml(_))
+    ));
+}
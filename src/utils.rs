@@ -1,21 +1,23 @@
 use crate::repository::Artifact;
 use serde::Deserialize;
+use std::path::{Path, PathBuf};
 
 #[derive(Deserialize)]
 #[serde(rename = "project")]
 struct Project {
+    #[serde(default)]
     pub dependencies: Dependencies,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Default)]
 #[serde(rename = "dependencies")]
 struct Dependencies {
-    #[serde(rename = "dependency")]
+    #[serde(rename = "dependency", default)]
     pub artifacts: Vec<Artifact>,
 }
 
 /// Parses the given contents of the pom.xml file, and returns a `Vec<Artifact>` containing all of
-/// the dependencies if there were no errors while parsing.
+/// the dependencies if there were no errors while parsing (*empty if the pom doesn't declare any*).
 pub fn dependencies_in_pom<T: AsRef<str>>(
     pom_contents: T,
 ) -> Result<Vec<Artifact>, serde_xml_rs::Error> {
@@ -26,6 +28,153 @@ pub fn dependencies_in_pom<T: AsRef<str>>(
     )
 }
 
+#[derive(Deserialize)]
+#[serde(rename = "parent")]
+struct PomParent {
+    #[serde(rename = "groupId")]
+    pub group_id: Option<String>,
+
+    pub version: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "project")]
+struct PomCoordinates {
+    #[serde(rename = "groupId")]
+    pub group_id: Option<String>,
+
+    #[serde(rename = "artifactId")]
+    pub artifact_id: Option<String>,
+
+    pub version: Option<String>,
+
+    pub parent: Option<PomParent>,
+}
+
+/// Parses the given contents of the pom.xml file, and returns the coordinates of the
+/// project described by it. The `groupId` and `version` get inherited from the `<parent>`
+/// element when the project doesn't declare them.
+///
+/// Returns `Ok(None)` if the pom is well formed, but some of the coordinates are missing.
+pub fn artifact_in_pom<T: AsRef<str>>(
+    pom_contents: T,
+) -> Result<Option<Artifact>, serde_xml_rs::Error> {
+    let project = serde_xml_rs::from_str::<PomCoordinates>(trim_xml_file(pom_contents.as_ref()))?;
+    let (parent_group_id, parent_version) = match project.parent {
+        Some(parent) => (parent.group_id, parent.version),
+        None => (None, None),
+    };
+
+    Ok(
+        match (
+            project.group_id.or(parent_group_id),
+            project.artifact_id,
+            project.version.or(parent_version),
+        ) {
+            (Some(group_id), Some(artifact_id), Some(version)) => {
+                Some(Artifact::new(group_id, artifact_id, version))
+            }
+            _ => None,
+        },
+    )
+}
+
+/// Guesses the artifact id and the version of an artifact from the stem of its
+/// filename, following the `artifactId-version` convention (*i.e. `junit-jupiter-api-5.10.2`*).
+/// The version is considered to start at the first dash followed by a digit.
+///
+/// The group id cannot be inferred from a filename, so it's left empty.
+pub fn artifact_from_filename<T: AsRef<str>>(file_stem: T) -> Artifact {
+    let file_stem = file_stem.as_ref();
+    let version_start = file_stem
+        .match_indices('-')
+        .map(|(index, _)| index)
+        .find(|index| {
+            file_stem[index + 1..]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_digit())
+        });
+
+    match version_start {
+        Some(index) => Artifact::new("", &file_stem[..index], &file_stem[index + 1..]),
+        None => Artifact::new("", file_stem, ""),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "metadata")]
+struct Metadata {
+    #[serde(default)]
+    pub versioning: Versioning,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename = "versioning")]
+struct Versioning {
+    #[serde(default)]
+    pub versions: Versions,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename = "versions")]
+struct Versions {
+    #[serde(rename = "version", default)]
+    pub versions: Vec<String>,
+}
+
+/// Parses the given contents of a maven-metadata.xml file, and returns the versions
+/// listed in it, in the same order (*from the oldest to the newest one*).
+pub fn versions_in_metadata<T: AsRef<str>>(
+    metadata_contents: T,
+) -> Result<Vec<String>, serde_xml_rs::Error> {
+    Ok(
+        serde_xml_rs::from_str::<Metadata>(trim_xml_file(metadata_contents.as_ref()))?
+            .versioning
+            .versions
+            .versions,
+    )
+}
+
+/// Builds the contents of the maven-metadata.xml file of the artifact after deploying
+/// its version, adding it to the versions listed in the given contents (*or starting
+/// a new file if there's none*). The version becomes the latest one, as well as the
+/// release if it isn't a snapshot.
+///
+/// Only the coordinates and the versioning are kept from the given contents.
+pub fn metadata_with_version(
+    metadata_contents: Option<&str>,
+    artifact: &Artifact,
+) -> Result<String, serde_xml_rs::Error> {
+    let mut versions = match metadata_contents {
+        Some(metadata_contents) => versions_in_metadata(metadata_contents)?,
+        None => Vec::new(),
+    };
+    if !versions.contains(&artifact.version) {
+        versions.push(artifact.version.clone());
+    }
+
+    let mut metadata = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<metadata>\n");
+    metadata += &format!("  <groupId>{}</groupId>\n", artifact.group_id);
+    metadata += &format!("  <artifactId>{}</artifactId>\n", artifact.artifact_id);
+    metadata += "  <versioning>\n";
+    metadata += &format!("    <latest>{}</latest>\n", artifact.version);
+    if let Some(release) = versions
+        .iter()
+        .rev()
+        .find(|version| !version.ends_with("-SNAPSHOT"))
+    {
+        metadata += &format!("    <release>{release}</release>\n");
+    }
+    metadata += "    <versions>\n";
+    for version in &versions {
+        metadata += &format!("      <version>{version}</version>\n");
+    }
+    metadata += "    </versions>\n  </versioning>\n</metadata>\n";
+
+    Ok(metadata)
+}
+
 /// Removes the first line of xml (*the XML declaration*), making it
 /// parseable for `serde_xml_rs`. If the line doesn't start with '<?xml...',
 /// this first line won't be trimmed, and the original contents passed will be returned.
@@ -37,3 +186,26 @@ fn trim_xml_file(pom_contents: &str) -> &str {
         pom_contents
     }
 }
+
+/// Returns the hexadecimal SHA-1 digest of the given contents, as found in
+/// the `.sha1` files of remote repositories.
+pub fn sha1_hex<T: AsRef<[u8]>>(contents: T) -> String {
+    sha1_smol::Sha1::from(contents).digest().to_string()
+}
+
+/// Path of the file containing the checksum of the given file (*the same
+/// path with an extra `.sha1` extension*).
+pub fn checksum_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    let mut checksum_path = path.as_ref().as_os_str().to_owned();
+    checksum_path.push(".sha1");
+    PathBuf::from(checksum_path)
+}
+
+/// Checks if the given group id is the same as `group`, or one of its subgroups
+/// (*i.e. `org.junit.jupiter` matches `org.junit`, but `org.junitx` doesn't*).
+pub fn group_matches<T: AsRef<str>>(group_id: T, group: T) -> bool {
+    let (group_id, group) = (group_id.as_ref(), group.as_ref());
+    group_id
+        .strip_prefix(group)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
@@ -0,0 +1,493 @@
+use xml::{common::Position, reader::XmlEvent, EventReader};
+
+/// Namespace of the elements of a POM following the Maven 4.0.0 model.
+pub const POM_NAMESPACE: &str = "http://maven.apache.org/POM/4.0.0";
+
+/// A violation of the Maven 4.0.0 POM schema found when validating a pom. The
+/// position (*line and column, starting at 1*) points to the element responsible
+/// for the violation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PomDiagnostic {
+    pub line: u64,
+    pub column: u64,
+    pub kind: PomDiagnosticKind,
+}
+
+/// The different kinds of violations that can be found in a POM.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PomDiagnosticKind {
+    /// The pom isn't well formed XML. Contains the message of the parser.
+    MalformedXml(String),
+
+    /// The root element of the pom isn't `<project>`.
+    UnexpectedRoot(String),
+
+    /// The element doesn't belong to the POM namespace ([`POM_NAMESPACE`]).
+    /// Contains the namespace of the element, if it has one.
+    WrongNamespace(Option<String>),
+
+    /// The `<modelVersion>` of the project isn't `4.0.0`.
+    UnsupportedModelVersion(String),
+
+    /// A required element is missing.
+    MissingElement { parent: String, element: String },
+
+    /// The element isn't allowed inside of its parent.
+    UnexpectedElement { parent: String, element: String },
+
+    /// The element can only appear once inside of its parent.
+    DuplicateElement { parent: String, element: String },
+}
+
+impl std::fmt::Display for PomDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.kind {
+            PomDiagnosticKind::MalformedXml(msg) => write!(f, "malformed XML: {msg}"),
+            PomDiagnosticKind::UnexpectedRoot(root) => {
+                write!(f, "expected <project> as the root element, found <{root}>")
+            }
+            PomDiagnosticKind::WrongNamespace(Some(namespace)) => {
+                write!(
+                    f,
+                    "expected namespace '{POM_NAMESPACE}', found '{namespace}'"
+                )
+            }
+            PomDiagnosticKind::WrongNamespace(None) => {
+                write!(f, "expected namespace '{POM_NAMESPACE}', found none")
+            }
+            PomDiagnosticKind::UnsupportedModelVersion(version) => {
+                write!(f, "unsupported model version '{version}', expected '4.0.0'")
+            }
+            PomDiagnosticKind::MissingElement { parent, element } => {
+                write!(f, "<{parent}> is missing the required element <{element}>")
+            }
+            PomDiagnosticKind::UnexpectedElement { parent, element } => {
+                write!(f, "<{element}> is not allowed inside of <{parent}>")
+            }
+            PomDiagnosticKind::DuplicateElement { parent, element } => {
+                write!(f, "<{element}> can only appear once inside of <{parent}>")
+            }
+        }
+    }
+}
+
+/// Content model of a complex element of the POM schema.
+struct ElementRule {
+    name: &'static str,
+
+    /// Elements allowed as children.
+    children: &'static [&'static str],
+
+    /// Whether the children can appear more than once (*a list, such as `<dependencies>`*),
+    /// or at most once.
+    repeatable: bool,
+
+    /// Children that must be present.
+    required: &'static [&'static str],
+}
+
+/// Pragmatic subset of the Maven 4.0.0 XSD. The contents of elements not listed
+/// here (*i.e. `<build>`, `<properties>` or plugin configurations*) are not checked.
+///
+/// The XSD itself doesn't require any element, so the required ones are taken from
+/// the rules Maven enforces when building the model of a project.
+const ELEMENT_RULES: &[ElementRule] = &[
+    ElementRule {
+        name: "project",
+        children: &[
+            "parent",
+            "modelVersion",
+            "groupId",
+            "artifactId",
+            "version",
+            "packaging",
+            "name",
+            "description",
+            "url",
+            "inceptionYear",
+            "organization",
+            "licenses",
+            "developers",
+            "contributors",
+            "mailingLists",
+            "prerequisites",
+            "modules",
+            "scm",
+            "issueManagement",
+            "ciManagement",
+            "distributionManagement",
+            "properties",
+            "dependencyManagement",
+            "dependencies",
+            "repositories",
+            "pluginRepositories",
+            "build",
+            "reports",
+            "reporting",
+            "profiles",
+        ],
+        repeatable: false,
+        required: &["modelVersion", "artifactId"],
+    },
+    ElementRule {
+        name: "parent",
+        children: &["groupId", "artifactId", "version", "relativePath"],
+        repeatable: false,
+        required: &["groupId", "artifactId", "version"],
+    },
+    ElementRule {
+        name: "dependencyManagement",
+        children: &["dependencies"],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "dependencies",
+        children: &["dependency"],
+        repeatable: true,
+        required: &[],
+    },
+    ElementRule {
+        name: "dependency",
+        children: &[
+            "groupId",
+            "artifactId",
+            "version",
+            "type",
+            "classifier",
+            "scope",
+            "systemPath",
+            "exclusions",
+            "optional",
+        ],
+        repeatable: false,
+        required: &["groupId", "artifactId"],
+    },
+    ElementRule {
+        name: "exclusions",
+        children: &["exclusion"],
+        repeatable: true,
+        required: &[],
+    },
+    ElementRule {
+        name: "exclusion",
+        children: &["groupId", "artifactId"],
+        repeatable: false,
+        required: &["groupId", "artifactId"],
+    },
+    ElementRule {
+        name: "organization",
+        children: &["name", "url"],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "licenses",
+        children: &["license"],
+        repeatable: true,
+        required: &[],
+    },
+    ElementRule {
+        name: "license",
+        children: &["name", "url", "distribution", "comments"],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "developers",
+        children: &["developer"],
+        repeatable: true,
+        required: &[],
+    },
+    ElementRule {
+        name: "developer",
+        children: &[
+            "id",
+            "name",
+            "email",
+            "url",
+            "organization",
+            "organizationUrl",
+            "roles",
+            "timezone",
+            "properties",
+        ],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "contributors",
+        children: &["contributor"],
+        repeatable: true,
+        required: &[],
+    },
+    ElementRule {
+        name: "contributor",
+        children: &[
+            "name",
+            "email",
+            "url",
+            "organization",
+            "organizationUrl",
+            "roles",
+            "timezone",
+            "properties",
+        ],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "roles",
+        children: &["role"],
+        repeatable: true,
+        required: &[],
+    },
+    ElementRule {
+        name: "mailingLists",
+        children: &["mailingList"],
+        repeatable: true,
+        required: &[],
+    },
+    ElementRule {
+        name: "mailingList",
+        children: &[
+            "name",
+            "subscribe",
+            "unsubscribe",
+            "post",
+            "archive",
+            "otherArchives",
+        ],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "prerequisites",
+        children: &["maven"],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "modules",
+        children: &["module"],
+        repeatable: true,
+        required: &[],
+    },
+    ElementRule {
+        name: "scm",
+        children: &["connection", "developerConnection", "tag", "url"],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "issueManagement",
+        children: &["system", "url"],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "ciManagement",
+        children: &["system", "url", "notifiers"],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "repositories",
+        children: &["repository"],
+        repeatable: true,
+        required: &[],
+    },
+    ElementRule {
+        name: "pluginRepositories",
+        children: &["pluginRepository"],
+        repeatable: true,
+        required: &[],
+    },
+    ElementRule {
+        name: "repository",
+        children: &["releases", "snapshots", "id", "name", "url", "layout"],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "pluginRepository",
+        children: &["releases", "snapshots", "id", "name", "url", "layout"],
+        repeatable: false,
+        required: &[],
+    },
+    ElementRule {
+        name: "profiles",
+        children: &["profile"],
+        repeatable: true,
+        required: &[],
+    },
+    ElementRule {
+        name: "profile",
+        children: &[
+            "id",
+            "activation",
+            "build",
+            "modules",
+            "distributionManagement",
+            "properties",
+            "dependencyManagement",
+            "dependencies",
+            "repositories",
+            "pluginRepositories",
+            "reports",
+            "reporting",
+        ],
+        repeatable: false,
+        required: &[],
+    },
+];
+
+/// An element being validated.
+struct Frame {
+    name: String,
+    rule: Option<&'static ElementRule>,
+    children: Vec<String>,
+    line: u64,
+    column: u64,
+}
+
+/// Validates the given contents of a pom.xml file against a pragmatic subset of the
+/// Maven 4.0.0 XSD, returning every violation found (*an empty `Vec` means the pom is valid*).
+///
+/// Besides the structure of the elements, the pom must declare the POM namespace, its
+/// `<modelVersion>` must be `4.0.0`, and it must contain the coordinates of the project
+/// (*`groupId` and `version` can be inherited from the `<parent>`*).
+///
+/// Malformed XML stops the validation, being the last diagnostic returned.
+pub fn validate_pom<T: AsRef<str>>(pom_contents: T) -> Vec<PomDiagnostic> {
+    let mut reader = EventReader::from_str(pom_contents.as_ref().trim_start());
+    let mut diagnostics = Vec::new();
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut model_version = String::new();
+    let mut root_namespace: Option<String> = None;
+
+    loop {
+        let event = reader.next();
+        let position = reader.position();
+        let (line, column) = (position.row + 1, position.column + 1);
+        let mut diagnostic = |kind| {
+            diagnostics.push(PomDiagnostic { line, column, kind });
+        };
+
+        match event {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                let element = name.local_name;
+                let checked = stack.is_empty() || stack.iter().all(|frame| frame.rule.is_some());
+
+                // Only the root and the elements redeclaring the namespace get reported,
+                // since the rest of them inherit it.
+                if stack.is_empty() {
+                    root_namespace = name.namespace.clone();
+                }
+                if checked
+                    && (stack.is_empty() || name.namespace != root_namespace)
+                    && name.namespace.as_deref() != Some(POM_NAMESPACE)
+                {
+                    diagnostic(PomDiagnosticKind::WrongNamespace(name.namespace.clone()));
+                }
+
+                match stack.last_mut() {
+                    None if element != "project" => {
+                        diagnostic(PomDiagnosticKind::UnexpectedRoot(element.clone()));
+                    }
+                    Some(Frame {
+                        name: parent,
+                        rule: Some(rule),
+                        children,
+                        ..
+                    }) => {
+                        if !rule.children.contains(&element.as_str()) {
+                            diagnostic(PomDiagnosticKind::UnexpectedElement {
+                                parent: parent.clone(),
+                                element: element.clone(),
+                            });
+                        } else if !rule.repeatable && children.contains(&element) {
+                            diagnostic(PomDiagnosticKind::DuplicateElement {
+                                parent: parent.clone(),
+                                element: element.clone(),
+                            });
+                        }
+                        children.push(element.clone());
+                    }
+                    _ => {}
+                }
+
+                let rule = if checked {
+                    ELEMENT_RULES.iter().find(|rule| rule.name == element)
+                } else {
+                    None
+                };
+                stack.push(Frame {
+                    name: element,
+                    rule,
+                    children: Vec::new(),
+                    line,
+                    column,
+                });
+            }
+            Ok(XmlEvent::Characters(text)) => {
+                if stack.len() == 2 && stack[1].name == "modelVersion" {
+                    model_version.push_str(&text);
+                }
+            }
+            Ok(XmlEvent::EndElement { .. }) => {
+                let Some(frame) = stack.pop() else {
+                    continue;
+                };
+                let Some(rule) = frame.rule else {
+                    continue;
+                };
+
+                // The coordinates of the project can be inherited from its parent.
+                let inherited: &[&str] =
+                    if stack.is_empty() && !frame.children.iter().any(|child| child == "parent") {
+                        &["groupId", "version"]
+                    } else {
+                        &[]
+                    };
+                rule.required
+                    .iter()
+                    .chain(inherited)
+                    .filter(|required| !frame.children.iter().any(|child| child == *required))
+                    .for_each(|required| {
+                        diagnostics.push(PomDiagnostic {
+                            line: frame.line,
+                            column: frame.column,
+                            kind: PomDiagnosticKind::MissingElement {
+                                parent: frame.name.clone(),
+                                element: required.to_string(),
+                            },
+                        })
+                    });
+
+                if stack.is_empty()
+                    && frame.name == "project"
+                    && frame.children.iter().any(|child| child == "modelVersion")
+                    && model_version.trim() != "4.0.0"
+                {
+                    diagnostics.push(PomDiagnostic {
+                        line: frame.line,
+                        column: frame.column,
+                        kind: PomDiagnosticKind::UnsupportedModelVersion(
+                            model_version.trim().to_string(),
+                        ),
+                    });
+                }
+            }
+            Ok(XmlEvent::EndDocument) => break,
+            Ok(_) => {}
+            Err(e) => {
+                diagnostic(PomDiagnosticKind::MalformedXml(e.msg().to_string()));
+                break;
+            }
+        }
+    }
+
+    diagnostics
+}